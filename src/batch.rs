@@ -0,0 +1,138 @@
+//! # Batch Scoring
+//!
+//! Scores many round files in one process and summarises them as a table,
+//! one row per file. A file that cannot be read, parsed or scored gets an
+//! error row; it never stops the other files from being scored.
+
+use crate::{
+    error::OrtalabError,
+    io::parse_round,
+    poker::{
+        breakdown::ScoreBreakdown,
+        explain::Explain,
+        jokers::JokerRegistry,
+        luck::Luck,
+        scoring::{score_exact, score_with_luck},
+    },
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+};
+
+/// The outcome of scoring one file.
+#[derive(Debug)]
+pub struct BatchEntry {
+    pub file: PathBuf,
+    pub result: Result<ScoreBreakdown, OrtalabError>,
+}
+
+/// The outcome of scoring every file, in the order they were given.
+///
+/// Its [`Display`] renders the summary table.
+///
+/// # Example
+/// ```
+/// use std::path::PathBuf;
+/// use ortalab::batch::score_files;
+///
+/// let report = score_files(
+///     &[
+///         PathBuf::from("stage01_examples/pair.yml"),
+///         PathBuf::from("missing.yml"),
+///     ],
+///     false,
+///     0,
+/// );
+///
+/// assert_eq!(report.entries.len(), 2);
+/// assert_eq!(report.failures(), 1);
+///
+/// let table = report.to_string();
+/// assert!(table.starts_with("file"));
+/// assert!(table.contains("stage01_examples/pair.yml"));
+/// assert!(table.contains("missing.yml"));
+/// ```
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchReport {
+    /// How many files could not be scored.
+    pub fn failures(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.result.is_err())
+            .count()
+    }
+}
+
+/// Reads and scores every file in `files`, in order.
+///
+/// # Arguments
+/// * `files` — The round files to score (see
+///   [`collect_round_files`](crate::io::collect_round_files)).
+/// * `exact` — Whether to score with exact arithmetic (see
+///   [`score_exact`]); the table then shows the nearest `f64` of the chips
+///   and mult.
+/// * `seed` — The seed every round's chances are rolled with (see
+///   [`Luck::seeded`]).
+///
+/// # Returns
+/// A [`BatchReport`] with one entry per file.
+pub fn score_files(files: &[PathBuf], exact: bool, seed: u64) -> BatchReport {
+    let score = |round| {
+        let luck = Luck::seeded(seed);
+        let mut explain = Explain::disabled();
+        match exact {
+            true => score_exact(round, &luck, &mut explain).map(|breakdown| breakdown.to_f64()),
+            false => score_with_luck(round, JokerRegistry::builtin(), &luck, &mut explain),
+        }
+    };
+    let entries = files
+        .iter()
+        .map(|file| BatchEntry {
+            file: file.clone(),
+            result: parse_round(file).and_then(score),
+        })
+        .collect();
+
+    BatchReport { entries }
+}
+
+impl Display for BatchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let width = self
+            .entries
+            .iter()
+            .map(|entry| entry.file.display().to_string().chars().count())
+            .chain(std::iter::once("file".len()))
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<width$}  {:<16} {:>10} {:>10} {:>12}",
+            "file", "hand", "chips", "mult", "score"
+        )?;
+
+        for entry in &self.entries {
+            let file = entry.file.display();
+            match &entry.result {
+                Ok(breakdown) => {
+                    let (chips, mult) = breakdown.after_jokers;
+                    writeln!(
+                        f,
+                        "{file:<width$}  {:<16} {chips:>10} {mult:>10} {:>12}",
+                        breakdown.hand.to_string(),
+                        breakdown.score
+                    )?;
+                }
+                Err(err) => writeln!(f, "{file:<width$}  error: {err}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
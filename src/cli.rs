@@ -1,10 +1,114 @@
-use clap::Parser;
+use crate::poker::{deck::DEFAULT_HAND_SIZE, joker_slot::JokerSlot, luck::DEFAULT_SEED};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Opts {
-    pub file: PathBuf,
+    #[command(subcommand)]
+    pub command: Option<Command>,
 
+    /// The round files to score: files, directories or glob patterns
+    /// (`-` reads from stdin). Several rounds are summarised as a table.
+    #[arg(required = true)]
+    pub files: Vec<PathBuf>,
+
+    /// Print every scoring step with the running chips x mult
+    /// (single round only).
     #[arg(long)]
     pub explain: bool,
+
+    /// Score with exact arithmetic instead of `f64`, so no step rounds.
+    #[arg(long)]
+    pub exact: bool,
+
+    /// The seed every chance (Bloodstone, Space Joker, Lucky cards, Glass)
+    /// is rolled with.
+    #[arg(long, default_value_t = DEFAULT_SEED)]
+    pub seed: u64,
+
+    /// Print the chance of every possible score and the expected score,
+    /// instead of one outcome (single round only).
+    #[arg(long, conflicts_with_all = ["exact", "explain", "seed"])]
+    pub expected: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check a round file and report every rule it breaks.
+    Validate {
+        /// The round file to check (`-` reads from stdin).
+        file: PathBuf,
+    },
+
+    /// Find the best cards to play from every card in a round file
+    /// (played and held alike).
+    Solve {
+        /// The round file holding the hand (`-` reads from stdin).
+        file: PathBuf,
+    },
+
+    /// Find the Joker order that scores highest for a round file.
+    Arrange {
+        /// The round file whose Jokers are arranged (`-` reads from stdin).
+        file: PathBuf,
+
+        /// Maximise the average score over these round files (files,
+        /// directories or glob patterns) instead of `file`'s own round.
+        #[arg(long, num_args = 1..)]
+        average_over: Vec<PathBuf>,
+    },
+
+    /// Deal many hands from a shuffled deck and report how they score.
+    Simulate {
+        /// A deck file (the standard 52 cards by default).
+        #[arg(long)]
+        deck: Option<PathBuf>,
+
+        /// The Jokers in play, left to right (e.g. `--joker "Baron Foil"`).
+        #[arg(long = "joker")]
+        jokers: Vec<JokerSlot>,
+
+        /// How many cards are dealt into each hand.
+        #[arg(long, default_value_t = DEFAULT_HAND_SIZE)]
+        hand_size: usize,
+
+        /// How many hands to deal.
+        #[arg(long, default_value_t = 1000)]
+        hands: usize,
+
+        /// The seed every shuffle is drawn from; the same seed deals the
+        /// same hands.
+        #[arg(long, default_value_t = DEFAULT_SEED)]
+        seed: u64,
+
+        /// How each hand is played.
+        #[arg(long, value_enum, default_value_t = StrategyArg::Best)]
+        strategy: StrategyArg,
+    },
+
+    /// Score round files and compare them with their `expected_score`
+    /// and `expected_hand`.
+    Test {
+        /// The round files to check: files, directories or glob patterns.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+
+    /// Score round files both in `f64` and exactly, and report every stage
+    /// where the two disagree.
+    Compare {
+        /// The round files to compare: files, directories or glob patterns.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+}
+
+/// How `simulate` plays each hand.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum StrategyArg {
+    /// The highest scoring play.
+    Best,
+    /// The first five cards drawn.
+    FirstFive,
 }
@@ -0,0 +1,193 @@
+//! # Float vs Exact
+//!
+//! Scores every round twice, once in `f64` and once with
+//! [`Exact`] arithmetic, and reports every stage of the
+//! [`ScoreBreakdown`] where the two disagree. This is what `ortalab compare`
+//! runs, to find rounds where `f64` rounding changes the result.
+
+use crate::{
+    error::OrtalabError,
+    io::parse_round,
+    poker::{
+        big_score::BigScore,
+        breakdown::ScoreBreakdown,
+        explain::Explain,
+        luck::Luck,
+        number::{Exact, Number},
+        round::ScoringRound,
+        score_breakdown,
+        scoring::score_exact,
+    },
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+};
+
+/// One place where `f64` and exact scoring disagree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Disagreement {
+    /// The running `(chips, mult)` after a stage of the breakdown.
+    Stage {
+        stage: &'static str,
+        float: (f64, f64),
+        exact: (Exact, Exact),
+    },
+    /// The final score.
+    Score { float: BigScore, exact: BigScore },
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Disagreement::Stage {
+                stage,
+                float: (float_chips, float_mult),
+                exact: (exact_chips, exact_mult),
+            } => write!(
+                f,
+                "{stage}: f64 {float_chips} x {float_mult}, exact {exact_chips} x {exact_mult}"
+            ),
+            Disagreement::Score { float, exact } => {
+                write!(f, "score: f64 {float:#}, exact {exact:#}")
+            }
+        }
+    }
+}
+
+/// Scores `round` in `f64` and exactly, and lists every disagreement.
+///
+/// # Errors
+/// Returns the error of either scoring (e.g. [`OrtalabError::Overflow`]
+/// when the `f64` chips or mult become infinite).
+///
+/// # Example
+/// ```
+/// use ortalab::compare::{Disagreement, compare_round};
+/// use ortalab::poker::round::ScoringRound;
+///
+/// let round: ScoringRound = serde_yaml::from_str("cards_played: [K♥, K♠]").unwrap();
+/// assert!(compare_round(&round).unwrap().is_empty());
+///
+/// // 33 Steel Kings held with Baron: x1.5 sixty‑six times
+/// let held = vec!["K♠ Steel"; 33].join(", ");
+/// let round: ScoringRound = serde_yaml::from_str(&format!(
+///     "cards_played: [7♠ Bonus Foil]\ncards_held_in_hand: [{held}]\njokers: [Baron]"
+/// ))
+/// .unwrap();
+/// let disagreements = compare_round(&round).unwrap();
+/// assert!(matches!(
+///     disagreements.last(),
+///     Some(Disagreement::Score { .. })
+/// ));
+/// ```
+pub fn compare_round(round: &ScoringRound) -> Result<Vec<Disagreement>, OrtalabError> {
+    let float = score_breakdown(round.clone())?;
+    let exact = score_exact(round.clone(), &Luck::default(), &mut Explain::disabled())?;
+    Ok(disagreements(&float, &exact))
+}
+
+/// Every stage at which `float` differs from `exact`.
+fn disagreements(float: &ScoreBreakdown, exact: &ScoreBreakdown<Exact>) -> Vec<Disagreement> {
+    let stages = [
+        ("base", float.base, &exact.base),
+        ("after_cards", float.after_cards, &exact.after_cards),
+        ("after_held", float.after_held, &exact.after_held),
+        ("after_jokers", float.after_jokers, &exact.after_jokers),
+    ];
+
+    let mut disagreements = stages
+        .into_iter()
+        .filter(|&(_, (chips, mult), exact)| {
+            (Exact::from_f64(chips), Exact::from_f64(mult)) != *exact
+        })
+        .map(|(stage, float, exact)| Disagreement::Stage {
+            stage,
+            float,
+            exact: exact.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    if float.score != exact.score {
+        disagreements.push(Disagreement::Score {
+            float: float.score.clone(),
+            exact: exact.score.clone(),
+        });
+    }
+    disagreements
+}
+
+/// The comparison of every file, in the order they were given.
+///
+/// Its [`Display`] lists each file with its disagreements, then a summary.
+#[derive(Debug, Default)]
+pub struct ComparisonReport {
+    pub entries: Vec<(PathBuf, Result<Vec<Disagreement>, OrtalabError>)>,
+}
+
+impl ComparisonReport {
+    /// How many files could not be compared.
+    pub fn failures(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .count()
+    }
+
+    /// How many files scored differently in `f64` and exactly.
+    pub fn disagreements(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, result)| result.as_ref().is_ok_and(|found| !found.is_empty()))
+            .count()
+    }
+}
+
+/// Reads and compares every file in `files`, in order.
+///
+/// # Example
+/// ```
+/// use std::path::PathBuf;
+/// use ortalab::compare::compare_files;
+///
+/// let report = compare_files(&[PathBuf::from("stage01_examples/pair.yml")]);
+/// assert_eq!(report.disagreements(), 0);
+/// assert!(report.to_string().starts_with("same  stage01_examples/pair.yml"));
+/// ```
+pub fn compare_files(files: &[PathBuf]) -> ComparisonReport {
+    let entries = files
+        .iter()
+        .map(|file| {
+            let result = parse_round(file).and_then(|round| compare_round(&round));
+            (file.clone(), result)
+        })
+        .collect();
+
+    ComparisonReport { entries }
+}
+
+impl Display for ComparisonReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (file, result) in &self.entries {
+            let file = file.display();
+            match result {
+                Ok(found) if found.is_empty() => writeln!(f, "same  {file}")?,
+                Ok(found) => {
+                    writeln!(f, "DIFF  {file}")?;
+                    for disagreement in found {
+                        writeln!(f, "      {disagreement}")?;
+                    }
+                }
+                Err(err) => writeln!(f, "FAIL  {file}: {err}")?,
+            }
+        }
+
+        let disagreements = self.disagreements();
+        let failures = self.failures();
+        writeln!(
+            f,
+            "\n{} agree, {disagreements} disagree, {failures} failed",
+            self.entries.len() - disagreements - failures
+        )
+    }
+}
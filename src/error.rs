@@ -0,0 +1,116 @@
+//! # Errors
+//!
+//! Every way OrtaLab can fail, as one [`OrtalabError`] enum. Each variant
+//! maps to its own process exit code (see [`OrtalabError::exit_code`]) so
+//! scripts can tell a missing file from a malformed or an unplayable round.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    path::PathBuf,
+};
+
+/// An error raised while reading, parsing or scoring a round.
+///
+/// # Example
+/// ```
+/// use ortalab::error::OrtalabError;
+///
+/// let err = OrtalabError::InvalidRound("no cards played".to_string());
+/// assert_eq!(err.to_string(), "invalid round: no cards played");
+/// assert_eq!(err.exit_code(), 4);
+/// ```
+#[derive(Debug)]
+pub enum OrtalabError {
+    /// The round file (or stdin) could not be read.
+    Io { path: PathBuf, source: io::Error },
+
+    /// The input is not a valid YAML round.
+    Yaml(serde_yaml::Error),
+
+    /// The round parsed, but cannot be scored.
+    InvalidRound(String),
+
+    /// Some of the files in a batch could not be scored.
+    Batch { failed: usize, total: usize },
+
+    /// Some round files did not score what they expect.
+    Mismatch { failed: usize, total: usize },
+
+    /// The chips or mult grew past the range of `f64`.
+    Overflow,
+
+    /// A round's random effects have too many outcomes to score every one.
+    TooManyOutcomes { limit: usize },
+}
+
+impl OrtalabError {
+    /// The process exit code reported for this error.
+    ///
+    /// | Error            | Code |
+    /// |------------------|------|
+    /// | `Io`             | 2    |
+    /// | `Yaml`           | 3    |
+    /// | `InvalidRound`   | 4    |
+    /// | `Batch`          | 5    |
+    /// | `Mismatch`       | 6    |
+    /// | `Overflow`       | 7    |
+    /// | `TooManyOutcomes`| 8    |
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            OrtalabError::Io { .. } => 2,
+            OrtalabError::Yaml(_) => 3,
+            OrtalabError::InvalidRound(_) => 4,
+            OrtalabError::Batch { .. } => 5,
+            OrtalabError::Mismatch { .. } => 6,
+            OrtalabError::Overflow => 7,
+            OrtalabError::TooManyOutcomes { .. } => 8,
+        }
+    }
+}
+
+impl Display for OrtalabError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OrtalabError::Io { path, source } => {
+                write!(f, "cannot read `{}`: {source}", path.display())
+            }
+            OrtalabError::Yaml(err) => write!(f, "invalid round file: {err}"),
+            OrtalabError::InvalidRound(reason) => write!(f, "invalid round: {reason}"),
+            OrtalabError::Batch { failed, total } => {
+                write!(f, "{failed} of {total} round files could not be scored")
+            }
+            OrtalabError::Mismatch { failed, total } => {
+                write!(
+                    f,
+                    "{failed} of {total} round files did not match their expectations"
+                )
+            }
+            OrtalabError::Overflow => write!(f, "chips or mult are too large to score"),
+            OrtalabError::TooManyOutcomes { limit } => {
+                write!(f, "more than {limit} random outcomes to score")
+            }
+        }
+    }
+}
+
+impl Error for OrtalabError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OrtalabError::Io { source, .. } => Some(source),
+            OrtalabError::Yaml(err) => Some(err),
+            OrtalabError::InvalidRound(_)
+            | OrtalabError::Batch { .. }
+            | OrtalabError::Mismatch { .. }
+            | OrtalabError::Overflow
+            | OrtalabError::TooManyOutcomes { .. } => None,
+        }
+    }
+}
+
+impl From<serde_yaml::Error> for OrtalabError {
+    fn from(err: serde_yaml::Error) -> Self {
+        OrtalabError::Yaml(err)
+    }
+}
@@ -0,0 +1,196 @@
+//! # Golden Files
+//!
+//! Round files may record the result they are expected to produce
+//! (`expected_score` and/or `expected_hand`). [`check_files`] scores every
+//! file and compares it against those expectations, which is what
+//! `ortalab test` and the example regression tests run.
+
+use crate::{
+    error::OrtalabError,
+    io::parse_round,
+    poker::{big_score::BigScore, breakdown::ScoreBreakdown, score_breakdown},
+};
+use ortalib::PokerHand;
+use std::{
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+};
+
+/// One way a round's result differs from what its file expects.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mismatch {
+    Score {
+        expected: BigScore,
+        actual: BigScore,
+    },
+    Hand {
+        expected: PokerHand,
+        actual: PokerHand,
+    },
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Score { expected, actual } => {
+                write!(f, "expected score {expected:#}, got {actual:#}")
+            }
+            Mismatch::Hand { expected, actual } => {
+                write!(f, "expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+/// The outcome of checking one file.
+#[derive(Debug)]
+pub enum GoldenOutcome {
+    /// Every expectation in the file holds.
+    Pass,
+    /// The file records no expectation, so nothing was checked.
+    Unchecked,
+    /// At least one expectation does not hold.
+    Fail(Vec<Mismatch>),
+    /// The file could not be read, parsed or scored.
+    Error(OrtalabError),
+}
+
+/// The outcome of checking every file, in the order they were given.
+///
+/// Its [`Display`] renders one line per file and a summary line.
+///
+/// # Example
+/// ```
+/// use std::path::PathBuf;
+/// use ortalab::golden::check_files;
+///
+/// let report = check_files(&[PathBuf::from("stage01_examples/pair.yml")]);
+/// assert!(report.is_success());
+/// assert!(report.to_string().starts_with("ok    stage01_examples/pair.yml"));
+/// ```
+#[derive(Debug, Default)]
+pub struct GoldenReport {
+    pub entries: Vec<(PathBuf, GoldenOutcome)>,
+}
+
+impl GoldenReport {
+    /// How many files failed their expectations or could not be scored.
+    pub fn failures(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, outcome)| {
+                matches!(outcome, GoldenOutcome::Fail(_) | GoldenOutcome::Error(_))
+            })
+            .count()
+    }
+
+    /// Whether every file met its expectations.
+    pub fn is_success(&self) -> bool {
+        self.failures() == 0
+    }
+}
+
+/// Scores every file in `files` and compares it against its expectations.
+///
+/// # Arguments
+/// * `files` — The round files to check (see
+///   [`collect_round_files`](crate::io::collect_round_files)).
+///
+/// # Returns
+/// A [`GoldenReport`] with one entry per file.
+pub fn check_files(files: &[PathBuf]) -> GoldenReport {
+    let entries = files
+        .iter()
+        .map(|file| {
+            let outcome = match check_file(file) {
+                Ok(outcome) => outcome,
+                Err(err) => GoldenOutcome::Error(err),
+            };
+            (file.clone(), outcome)
+        })
+        .collect();
+
+    GoldenReport { entries }
+}
+
+/// Checks a single round file.
+fn check_file(file: &Path) -> Result<GoldenOutcome, OrtalabError> {
+    let round = parse_round(file)?;
+    let (expected_score, expected_hand) = (round.expected_score.clone(), round.expected_hand);
+    if expected_score.is_none() && expected_hand.is_none() {
+        return Ok(GoldenOutcome::Unchecked);
+    }
+
+    let breakdown = score_breakdown(round)?;
+    let mismatches = mismatches(&breakdown, expected_score, expected_hand);
+
+    if mismatches.is_empty() {
+        Ok(GoldenOutcome::Pass)
+    } else {
+        Ok(GoldenOutcome::Fail(mismatches))
+    }
+}
+
+/// Every expectation that `breakdown` does not meet.
+fn mismatches(
+    breakdown: &ScoreBreakdown,
+    expected_score: Option<BigScore>,
+    expected_hand: Option<PokerHand>,
+) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    if let Some(expected) = expected_hand
+        && expected != breakdown.hand
+    {
+        mismatches.push(Mismatch::Hand {
+            expected,
+            actual: breakdown.hand,
+        });
+    }
+
+    if let Some(expected) = expected_score
+        && expected != breakdown.score
+    {
+        mismatches.push(Mismatch::Score {
+            expected,
+            actual: breakdown.score.clone(),
+        });
+    }
+
+    mismatches
+}
+
+impl Display for GoldenReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (mut passed, mut unchecked) = (0, 0);
+
+        for (file, outcome) in &self.entries {
+            let file = file.display();
+            match outcome {
+                GoldenOutcome::Pass => {
+                    passed += 1;
+                    writeln!(f, "ok    {file}")?;
+                }
+                GoldenOutcome::Unchecked => {
+                    unchecked += 1;
+                    writeln!(f, "skip  {file} (no expectation)")?;
+                }
+                GoldenOutcome::Fail(mismatches) => {
+                    let reasons = mismatches.iter().map(ToString::to_string);
+                    writeln!(
+                        f,
+                        "FAIL  {file}: {}",
+                        reasons.collect::<Vec<_>>().join("; ")
+                    )?;
+                }
+                GoldenOutcome::Error(err) => writeln!(f, "FAIL  {file}: {err}")?,
+            }
+        }
+
+        writeln!(
+            f,
+            "\n{passed} passed, {} failed, {unchecked} without expectation",
+            self.failures()
+        )
+    }
+}
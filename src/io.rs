@@ -1,41 +1,121 @@
 use std::{
-    error::Error,
-    fs::File,
-    io::{Read, stdin},
-    path::Path,
+    fs::{self, File},
+    io::{self, Read, stdin},
+    path::{Path, PathBuf},
 };
 
-use crate::cli::Opts;
-use ortalib::Round;
+use crate::{error::OrtalabError, poker::round::ScoringRound};
 
-/// Parses a poker round from the given CLI options.
+/// Parses a poker round from a file.
 ///
 /// # Arguments
-/// * `opts` - Command-line options specifying the input source.
+/// * `path` - The round file to read, or `-` to read from stdin.
 ///
 /// # Returns
-/// A parsed `Round` structure representing the poker game state.
+/// A parsed [`ScoringRound`] representing the poker game state.
 ///
 /// # Errors
-/// Returns an error if the input cannot be read or parsed.
+/// Returns [`OrtalabError::Io`] if the input cannot be read,
+/// [`OrtalabError::InvalidRound`] if it sets a key a round does not have
+/// (e.g. a misspelled `expected_score`), or [`OrtalabError::Yaml`] if it is
+/// not a valid round otherwise.
 ///
 /// # Example
-/// ```no_run
-/// use ortalab::{cli::Opts, io::parse_round};
-/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let opts = Opts::parse();
-/// let round = parse_round(&opts)?;
+/// ```
+/// use std::path::Path;
+/// use ortalab::io::parse_round;
+/// # fn main() -> Result<(), ortalab::error::OrtalabError> {
+/// let round = parse_round(Path::new("stage01_examples/five-of-a-kind.yml"))?;
+/// assert_eq!(round.cards_played.len(), 5);
 /// # Ok(())
 /// # }
 /// ```
-pub fn parse_round(opts: &Opts) -> Result<Round, Box<dyn Error>> {
+pub fn parse_round(path: &Path) -> Result<ScoringRound, OrtalabError> {
     let mut input = String::new();
-    if opts.file == Path::new("-") {
-        stdin().read_to_string(&mut input)?;
+    let read = if path == Path::new("-") {
+        stdin().read_to_string(&mut input)
     } else {
-        File::open(&opts.file)?.read_to_string(&mut input)?;
+        File::open(path).and_then(|mut file| file.read_to_string(&mut input))
+    };
+    read.map_err(|source| OrtalabError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    serde_yaml::from_str(&input).map_err(|err| {
+        let message = err.to_string();
+        if message.contains("unknown field `") {
+            OrtalabError::InvalidRound(message)
+        } else {
+            OrtalabError::Yaml(err)
+        }
+    })
+}
+
+/// Expands the inputs given on the command line into the round files to score.
+///
+/// Each input is, in order of precedence:
+/// - `-` (stdin) or an existing file, kept as is;
+/// - a directory, replaced by its `.yml` and `.yaml` files, sorted by name;
+/// - a glob pattern (e.g. `stage0*_examples/*.yml`), replaced by its matches,
+///   sorted by name.
+///
+/// Anything else (a missing file, a pattern matching nothing) is kept as is,
+/// so reading it later reports the error for that input.
+///
+/// # Example
+/// ```
+/// use std::path::PathBuf;
+/// use ortalab::io::collect_round_files;
+///
+/// let files = collect_round_files(&[
+///     PathBuf::from("stage02_examples"),
+///     PathBuf::from("stage01_examples/flush*.yml"),
+/// ]);
+///
+/// assert_eq!(files[0], PathBuf::from("stage02_examples/v1.yml"));
+/// assert!(files.contains(&PathBuf::from("stage01_examples/flush-five.yml")));
+/// assert!(files.iter().all(|file| file.extension().unwrap() == "yml"));
+/// ```
+pub fn collect_round_files(inputs: &[PathBuf]) -> Vec<PathBuf> {
+    inputs
+        .iter()
+        .flat_map(|input| {
+            if input == Path::new("-") || input.is_file() {
+                vec![input.clone()]
+            } else if input.is_dir() {
+                round_files_in(input).unwrap_or_else(|_| vec![input.clone()])
+            } else {
+                glob_round_files(input).unwrap_or_else(|| vec![input.clone()])
+            }
+        })
+        .collect()
+}
+
+/// The `.yml` and `.yaml` files directly inside `dir`, sorted by name.
+fn round_files_in(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_yaml = path
+            .extension()
+            .is_some_and(|extension| extension == "yml" || extension == "yaml");
+        if path.is_file() && is_yaml {
+            files.push(path);
+        }
     }
+    files.sort();
+    Ok(files)
+}
 
-    let round = serde_yaml::from_str(&input)?;
-    Ok(round)
+/// The files matching the glob `pattern`, sorted by name, or `None` if it is
+/// not a valid pattern or matches nothing.
+fn glob_round_files(pattern: &Path) -> Option<Vec<PathBuf>> {
+    let mut files: Vec<_> = glob::glob(pattern.to_str()?)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    (!files.is_empty()).then_some(files)
 }
@@ -7,8 +7,15 @@
 //! ## Example
 //! ```bash
 //! cargo run [round_file.yml]
+//! cargo run stage01_examples/ 'stage02_examples/*.yml'
+//! cargo run test stage01_examples/ stage02_examples/
+//! cargo run -- --exact [round_file.yml]
 //! ```
 
+pub mod batch;
 pub mod cli;
+pub mod compare;
+pub mod error;
+pub mod golden;
 pub mod io;
 pub mod poker;
@@ -1,6 +1,30 @@
-use clap::Parser;
-use ortalab::{cli::Opts, io::parse_round, poker::score};
-use std::error::Error;
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use itertools::Itertools;
+use ortalab::{
+    batch::score_files,
+    cli::{Command, Opts, StrategyArg},
+    compare::compare_files,
+    error::OrtalabError,
+    golden::check_files,
+    io::{collect_round_files, parse_round},
+    poker::{
+        arrangement::{arrange_jokers, arrange_jokers_over},
+        cards::PlayingCard,
+        deck::Deck,
+        explain::Explain,
+        joker_slot::JokerSlot,
+        jokers::JokerRegistry,
+        luck::Luck,
+        scoring::{score_exact, score_expected, score_with_luck},
+        simulation::{SimulationConfig, Strategy, simulate},
+        solver::solve,
+        validate,
+    },
+};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
 /// Entry point of the OrtaLab CLI.
 ///
@@ -8,28 +32,268 @@ use std::error::Error;
 /// - Parses command-line arguments into [`Opts`].
 /// - Reads and parses a poker round from input.
 /// - Computes the chip score and multiplier.
+/// - With `--explain`, prints every scoring step before the result.
 /// - Prints the final floored chip value.
 ///
+/// Given several files, directories or globs, every round is scored in turn
+/// and summarised as a table instead; `--explain` and `--expected` are then
+/// rejected, as they describe a single round.
+///
+/// `ortalab validate <file>` instead checks the round and lists every rule
+/// it breaks, `ortalab solve <file>` finds the best cards to play from the
+/// whole hand, `ortalab arrange <file>` finds the best Joker order,
+/// `ortalab simulate` deals and scores many hands, `ortalab test <dir>` checks every round against its
+/// `expected_score` and `expected_hand`, and `ortalab compare <dir>` reports
+/// where `f64` and exact scoring disagree. With `--exact`, rounds are scored
+/// with exact arithmetic. Chances are rolled with `--seed`, or with
+/// `--expected` every outcome is scored and the expected score printed.
+///
 /// # Errors
-/// Returns an error if parsing the round fails.
+/// If reading, parsing, validating or scoring the round fails, prints the
+/// error to stderr and exits with [`OrtalabError::exit_code`].
+fn main() -> ExitCode {
+    let opts = Opts::parse();
+    let result = match &opts.command {
+        Some(Command::Validate { file }) => run_validate(file),
+        Some(Command::Solve { file }) => run_solve(file),
+        Some(Command::Arrange { file, average_over }) => run_arrange(file, average_over),
+        Some(Command::Simulate {
+            deck,
+            jokers,
+            hand_size,
+            hands,
+            seed,
+            strategy,
+        }) => run_simulate(
+            deck.as_deref(),
+            jokers,
+            *hand_size,
+            *hands,
+            *seed,
+            *strategy,
+        ),
+        Some(Command::Test { paths }) => run_test(&collect_round_files(paths)),
+        Some(Command::Compare { paths }) => run_compare(&collect_round_files(paths)),
+        None => {
+            let files = collect_round_files(&opts.files);
+            if let [file] = files.as_slice()
+                && opts.files.len() == 1
+                && opts.files[0] == *file
+            {
+                if opts.expected {
+                    run_expected(file)
+                } else {
+                    run_score(file, opts.explain, opts.exact, opts.seed)
+                }
+            } else {
+                if opts.explain || opts.expected {
+                    let flag = if opts.explain {
+                        "--explain"
+                    } else {
+                        "--expected"
+                    };
+                    Opts::command()
+                        .error(
+                            ErrorKind::ArgumentConflict,
+                            format!("{flag} only applies to a single round file"),
+                        )
+                        .exit();
+                }
+                run_batch(&files, opts.exact, opts.seed)
+            }
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+/// Scores the round in `file` and prints the result.
 ///
 /// # Example
 /// ```no_run
-/// use ortalab::{cli::Opts, io::parse_round, poker::score};
-/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let opts = Opts::parse();
-/// let round = parse_round(&opts)?;
-/// let (chips, mult) = score(round);
-/// println!("{}", (chips * mult).floor());
+/// use std::path::Path;
+/// use ortalab::io::parse_round;
+/// use ortalab::poker::{explain::Explain, jokers::JokerRegistry, score_with_registry};
+/// # fn main() -> Result<(), ortalab::error::OrtalabError> {
+/// let round = parse_round(Path::new("round.yml"))?;
+/// let mut explain = Explain::new(true);
+/// let breakdown = score_with_registry(round, JokerRegistry::builtin(), &mut explain)?;
+/// print!("{explain}");
+/// println!("{}", breakdown.score);
 /// # Ok(())
 /// # }
 /// ```
-fn main() -> Result<(), Box<dyn Error>> {
-    let opts = Opts::parse();
-    let round = parse_round(&opts)?;
+fn run_score(file: &Path, explain: bool, exact: bool, seed: u64) -> Result<(), OrtalabError> {
+    let round = parse_round(file)?;
+
+    let luck = Luck::seeded(seed);
+    let mut explain = Explain::new(explain);
+    let score = if exact {
+        score_exact(round, &luck, &mut explain)?.score
+    } else {
+        score_with_luck(round, JokerRegistry::builtin(), &luck, &mut explain)?.score
+    };
+
+    print!("{explain}");
+    println!("{score}");
+    Ok(())
+}
 
-    let (chips, mult) = score(round);
+/// Scores the round in `file` under every outcome of its chances and prints
+/// the chance of each score and the expected score.
+fn run_expected(file: &Path) -> Result<(), OrtalabError> {
+    let round = parse_round(file)?;
+    print!("{}", score_expected(round)?);
+    Ok(())
+}
+
+/// Scores every round in `files` and prints the summary table.
+///
+/// # Errors
+/// Returns [`OrtalabError::Batch`] if any file could not be scored; every
+/// other file is still scored and listed.
+fn run_batch(files: &[PathBuf], exact: bool, seed: u64) -> Result<(), OrtalabError> {
+    let report = score_files(files, exact, seed);
+    print!("{report}");
 
-    println!("{}", (chips * mult).floor());
+    match report.failures() {
+        0 => Ok(()),
+        failed => Err(OrtalabError::Batch {
+            failed,
+            total: report.entries.len(),
+        }),
+    }
+}
+
+/// Finds and prints the best play from the hand in `file`.
+fn run_solve(file: &Path) -> Result<(), OrtalabError> {
+    let round = parse_round(file)?;
+    let solution = solve(&round)?;
+
+    let cards = |cards: &[PlayingCard]| cards.iter().map(ToString::to_string).join(", ");
+    println!("play: [{}]", cards(&solution.round.cards_played));
+    println!("hold: [{}]", cards(&solution.round.cards_held_in_hand));
+    println!("hand: {}", solution.breakdown.hand);
+    println!("{}", solution.breakdown.score);
     Ok(())
 }
+
+/// Finds and prints the best order for the Jokers in `file`, either for its
+/// own round or on average over the rounds in `average_over`.
+fn run_arrange(file: &Path, average_over: &[PathBuf]) -> Result<(), OrtalabError> {
+    let round = parse_round(file)?;
+    let arrangement = if average_over.is_empty() {
+        arrange_jokers(&round)?
+    } else {
+        let rounds = collect_round_files(average_over)
+            .iter()
+            .map(|file| parse_round(file))
+            .collect::<Result<Vec<_>, _>>()?;
+        arrange_jokers_over(&rounds, &round.jokers)?
+    };
+
+    let jokers = arrangement
+        .jokers
+        .iter()
+        .map(ToString::to_string)
+        .join(", ");
+    println!("jokers: [{jokers}]");
+    println!("{}", arrangement.score);
+    Ok(())
+}
+
+/// Deals and scores `hands` hands and prints the report.
+fn run_simulate(
+    deck: Option<&Path>,
+    jokers: &[JokerSlot],
+    hand_size: usize,
+    hands: usize,
+    seed: u64,
+    strategy: StrategyArg,
+) -> Result<(), OrtalabError> {
+    let deck = match deck {
+        Some(path) => Deck::load(path)?,
+        None => Deck::standard(),
+    };
+    let strategy = match strategy {
+        StrategyArg::Best => Strategy::Best,
+        StrategyArg::FirstFive => Strategy::FirstFive,
+    };
+
+    let report = simulate(&SimulationConfig {
+        deck,
+        jokers: jokers.to_vec(),
+        hand_size,
+        hands,
+        seed,
+        strategy,
+    })?;
+    print!("{report}");
+    Ok(())
+}
+
+/// Checks every round in `files` against its expectations and prints the
+/// outcome of each.
+///
+/// # Errors
+/// Returns [`OrtalabError::Mismatch`] if any file failed.
+fn run_test(files: &[PathBuf]) -> Result<(), OrtalabError> {
+    let report = check_files(files);
+    print!("{report}");
+
+    match report.failures() {
+        0 => Ok(()),
+        failed => Err(OrtalabError::Mismatch {
+            failed,
+            total: report.entries.len(),
+        }),
+    }
+}
+
+/// Scores every round in `files` in `f64` and exactly, and prints where
+/// they disagree.
+///
+/// # Errors
+/// Returns [`OrtalabError::Batch`] if any file could not be scored;
+/// disagreements alone are not an error.
+fn run_compare(files: &[PathBuf]) -> Result<(), OrtalabError> {
+    let report = compare_files(files);
+    print!("{report}");
+
+    match report.failures() {
+        0 => Ok(()),
+        failed => Err(OrtalabError::Batch {
+            failed,
+            total: report.entries.len(),
+        }),
+    }
+}
+
+/// Checks the round in `file` and prints every violation found.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if the round breaks any rule.
+fn run_validate(file: &Path) -> Result<(), OrtalabError> {
+    let round = parse_round(file)?;
+    let violations = validate(&round);
+
+    if violations.is_empty() {
+        println!("{}: ok", file.display());
+        return Ok(());
+    }
+
+    violations
+        .iter()
+        .for_each(|violation| println!("{}: {violation}", file.display()));
+    Err(OrtalabError::InvalidRound(format!(
+        "{} violation(s) in `{}`",
+        violations.len(),
+        file.display()
+    )))
+}
@@ -0,0 +1,305 @@
+//! # Joker Arrangement
+//!
+//! Jokers resolve left to right, so their order changes the score (+Mult
+//! before ×Mult, Polychrome editions, what Blueprint copies).
+//! [`arrange_jokers`] finds the order that scores highest for one round, and
+//! [`arrange_jokers_over`] the order with the highest average over several.
+//!
+//! The search tries every distinct order, with reductions that never change
+//! the answer:
+//!
+//! - identical Jokers are interchangeable, so orders that only swap them are
+//!   tried once;
+//! - unless a Blueprint or Brainstorm is in play, Jokers whose position
+//!   cannot matter are set aside and appended after the search. These are
+//!   the rule‑changing Jokers (e.g. Splash, Mime) and the Jokers that only
+//!   add Chips, when their edition does not touch Mult. Chips are only ever
+//!   added to, so adding them earlier or later gives the same final Chips;
+//! - unless a Blueprint or Brainstorm is in play, the card phases only depend
+//!   on the relative order of the on‑scored and on‑held Jokers. Their result
+//!   is memoised per round on that order, so most orders only replay the
+//!   Joker phase.
+
+use crate::{
+    error::OrtalabError,
+    poker::{
+        big_score::BigScore,
+        breakdown::ScoreBreakdown,
+        explain::Explain,
+        joker_effects::BRAINSTORM,
+        joker_slot::{JokerKind, JokerSlot},
+        jokers::{Activation, JokerContext, JokerRegistry, joker_application},
+        round::ScoringRound,
+        score_with_registry,
+    },
+};
+use ortalib::{Edition, Joker};
+use std::collections::HashMap;
+
+/// Jokers whose position never changes the score on their own: they only
+/// change the rules, or only add Chips.
+const ORDER_FREE_JOKERS: [Joker; 14] = [
+    Joker::FourFingers,
+    Joker::Shortcut,
+    Joker::Pareidolia,
+    Joker::Splash,
+    Joker::SmearedJoker,
+    Joker::Mime,
+    Joker::SockAndBuskin,
+    Joker::SlyJoker,
+    Joker::WilyJoker,
+    Joker::CleverJoker,
+    Joker::DeviousJoker,
+    Joker::CraftyJoker,
+    Joker::ScaryFace,
+    Joker::OddTodd,
+];
+
+/// The best Joker order found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arrangement {
+    /// The Jokers, left to right.
+    pub jokers: Vec<JokerSlot>,
+    /// The score of that order (the average, over several rounds).
+    pub score: f64,
+    /// How many orders were scored to find it.
+    pub evaluated: usize,
+}
+
+/// Finds the Joker order that maximises the score of `round`.
+///
+/// On a tie the current order is kept, so an already optimal round is
+/// returned unchanged.
+///
+/// # Errors
+/// Returns an [`OrtalabError`] if `round` cannot be scored.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::arrangement::arrange_jokers;
+/// use ortalab::poker::round::ScoringRound;
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [K♥, K♠]\njokers: [Joker Polychrome, Joker, Sly Joker]",
+/// )
+/// .unwrap();
+///
+/// // The Polychrome Joker should come last: (2 + 4 + 4) x 1.5
+/// let arrangement = arrange_jokers(&round).unwrap();
+/// assert_eq!(arrangement.jokers[1].to_string(), "Joker Polychrome");
+/// assert_eq!(arrangement.score, 1200.0); // (30 + 50) x 15
+/// assert_eq!(arrangement.evaluated, 2); // Sly Joker set aside, two Jokers alike
+/// ```
+pub fn arrange_jokers(round: &ScoringRound) -> Result<Arrangement, OrtalabError> {
+    arrange_jokers_over(std::slice::from_ref(round), &round.jokers)
+}
+
+/// Finds the order of `jokers` that maximises the average score over
+/// `rounds` (each round is scored with `jokers` in place of its own).
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if `rounds` is empty, or the error
+/// of any round that cannot be scored.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::arrangement::arrange_jokers_over;
+/// use ortalab::poker::round::ScoringRound;
+///
+/// let parse = |yaml: &str| serde_yaml::from_str::<ScoringRound>(yaml).unwrap();
+/// let rounds = [parse("cards_played: [K♥, K♠]"), parse("cards_played: [2♥]")];
+/// let jokers = parse("cards_played: []\njokers: [Joker Polychrome, Jolly Joker]").jokers;
+///
+/// let arrangement = arrange_jokers_over(&rounds, &jokers).unwrap();
+/// assert_eq!(arrangement.jokers[0].to_string(), "Jolly Joker");
+/// ```
+pub fn arrange_jokers_over(
+    rounds: &[ScoringRound],
+    jokers: &[JokerSlot],
+) -> Result<Arrangement, OrtalabError> {
+    if rounds.is_empty() {
+        return Err(OrtalabError::InvalidRound(
+            "no rounds to arrange Jokers for".to_string(),
+        ));
+    }
+
+    let (ordered, free) = split_order_free(jokers);
+    let mut scorer = Scorer::new(rounds, is_copier_exists(jokers));
+
+    let mut best: Option<Arrangement> = None;
+    let mut evaluated = 0;
+    for mut order in distinct_orders(&ordered) {
+        order.extend_from_slice(&free);
+        let score = scorer.average_score(&order)?;
+        evaluated += 1;
+
+        if best.as_ref().is_none_or(|best| score > best.score) {
+            best = Some(Arrangement {
+                jokers: order,
+                score,
+                evaluated,
+            });
+        }
+    }
+
+    let mut best = best.unwrap_or_else(|| Arrangement {
+        jokers: jokers.to_vec(),
+        score: 0.0,
+        evaluated,
+    });
+    best.evaluated = evaluated;
+    Ok(best)
+}
+
+/// Splits `jokers` into those whose order matters and those set aside
+/// (see the module docs), each keeping its current order.
+fn split_order_free(jokers: &[JokerSlot]) -> (Vec<JokerSlot>, Vec<JokerSlot>) {
+    if is_copier_exists(jokers) {
+        return (jokers.to_vec(), Vec::new());
+    }
+
+    jokers.iter().cloned().partition(|slot| {
+        let is_free = matches!(slot.joker, JokerKind::Standard(joker) if ORDER_FREE_JOKERS.contains(&joker))
+            && matches!(slot.edition, None | Some(Edition::Foil));
+        !is_free
+    })
+}
+
+/// Whether a Blueprint or Brainstorm is among `jokers`, making every
+/// position matter.
+fn is_copier_exists(jokers: &[JokerSlot]) -> bool {
+    jokers
+        .iter()
+        .any(|slot| slot.joker == Joker::Blueprint || slot.joker == BRAINSTORM)
+}
+
+/// Every distinct order of `items` (Jokers, or the cards of a play),
+/// starting with the current one.
+pub(crate) fn distinct_orders<T: Clone + PartialEq>(items: &[T]) -> Vec<Vec<T>> {
+    // Group identical items, keeping the order they first appear in
+    let mut kinds: Vec<(T, usize)> = Vec::new();
+    for item in items {
+        match kinds.iter_mut().find(|(kind, _)| kind == item) {
+            Some((_, count)) => *count += 1,
+            None => kinds.push((item.clone(), 1)),
+        }
+    }
+
+    let mut orders = Vec::new();
+    let mut current = Vec::with_capacity(items.len());
+    build_orders(&mut kinds, &mut current, items.len(), &mut orders);
+
+    // Try the current order first, so it wins any tie
+    if let Some(position) = orders.iter().position(|order| order == items) {
+        orders.swap(0, position);
+    }
+    orders
+}
+
+/// Extends `current` with every remaining item of `kinds` in turn.
+fn build_orders<T: Clone>(
+    kinds: &mut [(T, usize)],
+    current: &mut Vec<T>,
+    len: usize,
+    orders: &mut Vec<Vec<T>>,
+) {
+    if current.len() == len {
+        orders.push(current.clone());
+        return;
+    }
+
+    for index in 0..kinds.len() {
+        if kinds[index].1 == 0 {
+            continue;
+        }
+        kinds[index].1 -= 1;
+        current.push(kinds[index].0.clone());
+        build_orders(kinds, current, len, orders);
+        current.pop();
+        kinds[index].1 += 1;
+    }
+}
+
+/// Scores rounds under different Joker orders, memoising the card phases.
+struct Scorer<'a> {
+    rounds: &'a [ScoringRound],
+    registry: &'static JokerRegistry,
+    is_memoised: bool,
+    /// The breakdown of each round, keyed by round and the order of its
+    /// on‑scored and on‑held Jokers.
+    card_phases: HashMap<(usize, Vec<JokerSlot>), ScoreBreakdown>,
+}
+
+impl<'a> Scorer<'a> {
+    fn new(rounds: &'a [ScoringRound], is_copier_exists: bool) -> Self {
+        Self {
+            rounds,
+            registry: JokerRegistry::builtin(),
+            is_memoised: !is_copier_exists,
+            card_phases: HashMap::new(),
+        }
+    }
+
+    /// The average score of the rounds, each played with `jokers`.
+    fn average_score(&mut self, jokers: &[JokerSlot]) -> Result<f64, OrtalabError> {
+        let mut total = 0.0;
+        for index in 0..self.rounds.len() {
+            total += self.score(index, jokers)?;
+        }
+        Ok(total / self.rounds.len() as f64)
+    }
+
+    /// The score of round `index` played with `jokers`.
+    fn score(&mut self, index: usize, jokers: &[JokerSlot]) -> Result<f64, OrtalabError> {
+        let round = &self.rounds[index];
+        let with_jokers = || ScoringRound {
+            jokers: jokers.to_vec(),
+            ..round.clone()
+        };
+        if !self.is_memoised {
+            let breakdown =
+                score_with_registry(with_jokers(), self.registry, &mut Explain::disabled())?;
+            return Ok(breakdown.score.to_f64());
+        }
+
+        let key = (index, self.card_phase_jokers(jokers));
+        let breakdown = match self.card_phases.get(&key) {
+            Some(breakdown) => breakdown,
+            None => {
+                let breakdown =
+                    score_with_registry(with_jokers(), self.registry, &mut Explain::disabled())?;
+                self.card_phases.entry(key).or_insert(breakdown)
+            }
+        };
+
+        // Replay only the Joker phase on top of the memoised card phases
+        let ctx = JokerContext::new(
+            breakdown.hand,
+            jokers,
+            &breakdown.scoring_cards,
+            &round.cards_held_in_hand,
+        )
+        .with_round(&round.context);
+        let (chips, mult) = breakdown.after_held;
+        let (chips, mult) =
+            joker_application(self.registry, &ctx, chips, mult, &mut Explain::disabled());
+        let score = BigScore::from_product(chips, mult).ok_or(OrtalabError::Overflow)?;
+        Ok(score.to_f64())
+    }
+
+    /// The Jokers of `jokers` that fire during the card phases, in order.
+    fn card_phase_jokers(&self, jokers: &[JokerSlot]) -> Vec<JokerSlot> {
+        jokers
+            .iter()
+            .filter(|slot| {
+                self.registry.get(&slot.joker).is_some_and(|effect| {
+                    matches!(
+                        effect.activation(),
+                        Activation::OnScored | Activation::OnHeld
+                    )
+                })
+            })
+            .cloned()
+            .collect()
+    }
+}
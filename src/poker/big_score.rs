@@ -0,0 +1,244 @@
+//! # Big Score
+//!
+//! `Chips` and `Mult` are `f64`, but their product is not: a late‑game
+//! `chips × mult` quickly passes 2^53, where an `f64` can no longer hold
+//! every integer, and eventually `f64::MAX`. [`BigScore`] holds the final
+//! score exactly instead, as an arbitrary‑precision integer, so the product
+//! of two finite `f64`s (or of two [`Exact`](crate::poker::number::Exact)
+//! numbers) never rounds, overflows, or becomes `inf`/NaN.
+//!
+//! Scores are printed in full up to 15 digits and in the game's scientific
+//! notation from there on (`1.234e15`). Both forms parse back, so round files
+//! can record an exact `expected_score` of any size.
+
+use num_bigint::BigUint;
+use ortalib::{Chips, Mult};
+use serde::{Deserialize, Deserializer, de::Visitor};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Scores with this many digits or more print in scientific notation.
+const SCIENTIFIC_DIGITS: usize = 16;
+
+/// The largest power of ten a parsed score may have.
+const MAX_EXPONENT: u32 = 100_000;
+
+/// An exact, non‑negative integer score.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::big_score::BigScore;
+///
+/// // 2^53 + 1 cannot be represented by an f64, but the score stays exact
+/// let (chips, mult) = (3_002_399_751_580_331.0, 3.0);
+/// let score = BigScore::from_product(chips, mult).unwrap();
+/// assert_eq!(format!("{score:#}"), "9007199254740993");
+/// assert_eq!(chips * mult, 9_007_199_254_740_992.0);
+///
+/// let score = BigScore::from_product(1234.0, 1e12).unwrap();
+/// assert_eq!(score.to_string(), "1.234e15");
+/// assert_eq!(format!("{score:#}"), "1234000000000000");
+///
+/// // The mantissa is rounded, carrying into the exponent
+/// assert_eq!(BigScore::from(1_234_999_999_999_999).to_string(), "1.235e15");
+/// assert_eq!(BigScore::from(9_999_600_000_000_000).to_string(), "1.000e16");
+///
+/// // Far beyond f64::MAX, still no inf
+/// let score = BigScore::from_product(f64::MAX, f64::MAX).unwrap();
+/// assert_eq!(score.to_string(), "3.232e616");
+/// assert_eq!(score.to_f64(), f64::MAX);
+///
+/// assert!(BigScore::from_product(f64::INFINITY, 1.0).is_none());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BigScore(BigUint);
+
+impl BigScore {
+    /// A score of 0.
+    pub const ZERO: Self = Self(BigUint::ZERO);
+
+    /// `chips × mult` rounded down, exactly.
+    ///
+    /// A negative product scores 0.
+    ///
+    /// # Returns
+    /// `None` if `chips` or `mult` is infinite or NaN.
+    pub fn from_product(chips: Chips, mult: Mult) -> Option<Self> {
+        if !chips.is_finite() || !mult.is_finite() {
+            return None;
+        }
+        if chips <= 0.0 || mult <= 0.0 {
+            return Some(Self::ZERO);
+        }
+
+        let (chips_significand, chips_exponent) = decompose(chips);
+        let (mult_significand, mult_exponent) = decompose(mult);
+        Some(Self::from_dyadic(
+            BigUint::from(chips_significand) * mult_significand,
+            i64::from(chips_exponent + mult_exponent),
+        ))
+    }
+
+    /// `significand × 2^exponent` rounded down.
+    pub(crate) fn from_dyadic(significand: BigUint, exponent: i64) -> Self {
+        match exponent {
+            0.. => Self(significand << exponent.unsigned_abs()),
+            _ => Self(significand >> exponent.unsigned_abs()),
+        }
+    }
+
+    /// The nearest `f64`, or `f64::MAX` past its range.
+    pub fn to_f64(&self) -> f64 {
+        dyadic_to_f64(&self.0, 0).min(f64::MAX)
+    }
+
+    /// Every decimal digit of the score.
+    pub fn digits(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Splits a finite, positive `f64` into `significand × 2^exponent`.
+fn decompose(value: f64) -> (u64, i32) {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    match exponent {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), exponent - 1075),
+    }
+}
+
+/// `significand × 2^exponent` as an `f64`, keeping its top 64 bits.
+pub(crate) fn dyadic_to_f64(significand: &BigUint, exponent: i64) -> f64 {
+    if *significand == BigUint::ZERO {
+        return 0.0;
+    }
+    let dropped = significand.bits().saturating_sub(64);
+    let top = (significand >> dropped)
+        .iter_u64_digits()
+        .next()
+        .unwrap_or(0);
+    let exponent = (exponent + dropped as i64).clamp(i32::MIN.into(), i32::MAX.into());
+    top as f64 * 2f64.powi(exponent as i32)
+}
+
+impl From<u64> for BigScore {
+    fn from(value: u64) -> Self {
+        Self(BigUint::from(value))
+    }
+}
+
+/// Prints the score in full below 10^15 and as `1.234e15` from there on,
+/// with the mantissa rounded half up to 3 decimals. The alternate form
+/// (`{:#}`) always prints every digit.
+impl Display for BigScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = self.digits();
+        if f.alternate() || digits.len() < SCIENTIFIC_DIGITS {
+            return f.pad(&digits);
+        }
+        let mut exponent = digits.len() - 1;
+        let mut mantissa: u32 = digits[..4].parse().unwrap_or_default();
+        if digits.as_bytes()[4] >= b'5' {
+            mantissa += 1;
+        }
+        if mantissa == 10_000 {
+            mantissa = 1_000;
+            exponent += 1;
+        }
+        f.pad(&format!(
+            "{}.{:03}e{exponent}",
+            mantissa / 1_000,
+            mantissa % 1_000
+        ))
+    }
+}
+
+/// Parses a whole, non‑negative score written in full (`9007199254740993`),
+/// as a decimal (`60.0`) or in scientific notation (`1.234e15`), exactly.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::big_score::BigScore;
+///
+/// assert_eq!("60".parse(), Ok(BigScore::from(60)));
+/// assert_eq!("60.0".parse(), Ok(BigScore::from(60)));
+/// assert_eq!("1.234e15".parse(), Ok(BigScore::from(1_234_000_000_000_000)));
+///
+/// let score: BigScore = "3.232e616".parse().unwrap();
+/// assert_eq!(score.to_string(), "3.232e616");
+///
+/// assert!("1.5".parse::<BigScore>().is_err());
+/// assert!("-60".parse::<BigScore>().is_err());
+/// ```
+impl FromStr for BigScore {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid score `{s}`");
+        let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => {
+                let exponent = exponent.strip_prefix('+').unwrap_or(exponent);
+                (mantissa, exponent.parse::<i64>().map_err(|_| invalid())?)
+            }
+            None => (s, 0),
+        };
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = format!("{whole}{fraction}");
+        if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return Err(invalid());
+        }
+
+        let significand: BigUint = digits.parse().map_err(|_| invalid())?;
+        let exponent = exponent - fraction.len() as i64;
+        let power = u32::try_from(exponent.unsigned_abs())
+            .ok()
+            .filter(|power| *power <= MAX_EXPONENT)
+            .ok_or_else(|| format!("Score `{s}` is out of range"))?;
+        let scale = BigUint::from(10u32).pow(power);
+
+        if exponent >= 0 {
+            Ok(Self(significand * scale))
+        } else if (&significand % &scale) == BigUint::ZERO {
+            Ok(Self(significand / scale))
+        } else {
+            Err(format!("Score `{s}` is not a whole number"))
+        }
+    }
+}
+
+/// Reads a score as written, so that scores beyond an `f64` stay exact.
+impl<'de> Deserialize<'de> for BigScore {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScoreVisitor;
+        impl Visitor<'_> for ScoreVisitor {
+            type Value = BigScore;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a whole, non-negative score")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(BigScore::from(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ScoreVisitor)
+    }
+}
@@ -0,0 +1,130 @@
+//! # Boss Blinds
+//!
+//! A round may be played against a [`BossBlind`] (`blind:` in the round
+//! file). Most boss blinds *debuff* some cards: a debuffed card still counts
+//! towards the poker hand, but gives no chips, its enhancement and edition
+//! do nothing, and no Joker triggers on it. The Flint instead halves the
+//! base chips and mult of the hand.
+//!
+//! ```yaml
+//! blind: The Club
+//! ```
+//!
+//! The Pillar lists the cards already played this ante:
+//!
+//! ```yaml
+//! blind:
+//!   The Pillar: [K♠, 3♥]
+//! ```
+
+use crate::poker::{cards::PlayingCard, jokers::JokerContext};
+use ortalib::{Card, Chips, Mult, Suit};
+use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
+
+/// A boss blind that changes how a round scores.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, PokerHand, Rank, Suit};
+/// use ortalab::poker::blinds::BossBlind;
+/// use ortalab::poker::jokers::JokerContext;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let ctx = JokerContext::new(PokerHand::HighCard, &[], &[], &[]);
+/// let king = PlayingCard::from(Card::new(Rank::King, Suit::Clubs, None, None));
+///
+/// assert!(BossBlind::Club.is_debuffed(&ctx, &king));
+/// assert!(BossBlind::Plant.is_debuffed(&ctx, &king));
+/// assert!(!BossBlind::Head.is_debuffed(&ctx, &king));
+///
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♣]\nblind:\n  The Pillar: [K♣]").unwrap();
+/// assert!(round.blind.unwrap().is_debuffed(&ctx, &king));
+///
+/// assert_eq!(BossBlind::Flint.apply_to_base((35.0, 4.0)), (18.0, 2.0));
+/// assert_eq!(BossBlind::Flint.apply_to_base((5.0, 1.0)), (3.0, 1.0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub enum BossBlind {
+    /// All ♣Club cards are debuffed.
+    #[serde(rename = "The Club")]
+    Club,
+    /// All ♠Spade cards are debuffed.
+    #[serde(rename = "The Goad")]
+    Goad,
+    /// All ♦Diamond cards are debuffed.
+    #[serde(rename = "The Window")]
+    Window,
+    /// All ♥Heart cards are debuffed.
+    #[serde(rename = "The Head")]
+    Head,
+    /// Base chips and mult are halved.
+    #[serde(rename = "The Flint")]
+    Flint,
+    /// All face cards are debuffed.
+    #[serde(rename = "The Plant")]
+    Plant,
+    /// Cards already played this ante are debuffed. A card matches by rank
+    /// and suit.
+    #[serde(rename = "The Pillar")]
+    Pillar(Vec<Card>),
+}
+
+impl BossBlind {
+    /// The suit this blind debuffs, if any.
+    pub fn debuffed_suit(&self) -> Option<Suit> {
+        match self {
+            BossBlind::Club => Some(Suit::Clubs),
+            BossBlind::Goad => Some(Suit::Spades),
+            BossBlind::Window => Some(Suit::Diamonds),
+            BossBlind::Head => Some(Suit::Hearts),
+            BossBlind::Flint | BossBlind::Plant | BossBlind::Pillar(_) => None,
+        }
+    }
+
+    /// Whether this blind debuffs `card`.
+    ///
+    /// Wild cards, and with Smeared Joker the other suit of the same colour,
+    /// count as the debuffed suit; with Pareidolia every card is a face card.
+    pub fn is_debuffed(&self, ctx: &JokerContext, card: &PlayingCard) -> bool {
+        match self {
+            BossBlind::Plant => ctx.is_face(card),
+            BossBlind::Pillar(played) => played
+                .iter()
+                .any(|other| other.rank == card.rank && other.suit == card.suit),
+            BossBlind::Flint => false,
+            _ => self
+                .debuffed_suit()
+                .is_some_and(|suit| ctx.is_suit(card, suit)),
+        }
+    }
+
+    /// The base chips and mult of the hand under this blind. The Flint
+    /// halves both, rounding half up and keeping at least 1.
+    pub fn apply_to_base(&self, (chips, mult): (Chips, Mult)) -> (Chips, Mult) {
+        match self {
+            BossBlind::Flint => {
+                let halve = |value: f64| (value / 2.0).round().max(1.0);
+                (halve(chips), halve(mult))
+            }
+            _ => (chips, mult),
+        }
+    }
+}
+
+impl Display for BossBlind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BossBlind::Club => "The Club",
+            BossBlind::Goad => "The Goad",
+            BossBlind::Window => "The Window",
+            BossBlind::Head => "The Head",
+            BossBlind::Flint => "The Flint",
+            BossBlind::Plant => "The Plant",
+            BossBlind::Pillar(_) => "The Pillar",
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,149 @@
+//! # Score Breakdown
+//!
+//! [`ScoreBreakdown`] reports how a round was scored: the detected hand,
+//! which cards scored, and the running chips × mult after each stage of the
+//! pipeline. It is returned by [`score_breakdown`](crate::poker::score_breakdown);
+//! [`score`](crate::poker::score) only keeps the final `(Chips, Mult)`.
+
+use crate::poker::{
+    big_score::BigScore, cards::PlayingCard, enhancements::ExtraEnhancement, levels::planet,
+    number::Number, seals::Seal,
+};
+use ortalib::PokerHand;
+use std::fmt::{self, Display, Formatter};
+
+/// Every stage of scoring one round.
+///
+/// Each `after_*` field is the running `(Chips, Mult)` once that stage has
+/// finished, so a stage's contribution is the difference to the one before.
+/// They are `f64`, or [`Exact`](crate::poker::number::Exact) when scored by
+/// [`score_exact`](crate::poker::scoring::score_exact).
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, PokerHand, Rank, Round, Suit};
+/// use ortalab::poker::{big_score::BigScore, score_breakdown};
+///
+/// let breakdown = score_breakdown(Round {
+///     cards_played: vec![Card::new(Rank::Ace, Suit::Hearts, None, None)],
+///     cards_held_in_hand: vec![],
+///     jokers: vec![],
+/// })
+/// .unwrap();
+///
+/// assert_eq!(breakdown.hand, PokerHand::HighCard);
+/// assert_eq!(breakdown.base, (5.0, 1.0));
+/// assert_eq!(breakdown.after_cards, (16.0, 1.0));
+/// assert_eq!(breakdown.score, BigScore::from(16));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown<N: Number = f64> {
+    /// The detected poker hand.
+    pub hand: PokerHand,
+    /// The level [`hand`](ScoreBreakdown::hand) was scored at.
+    pub hand_level: u32,
+    /// The played cards that scored, in played order.
+    pub scoring_cards: Vec<PlayingCard>,
+    /// The played cards that did not score, in played order.
+    pub unscored_cards: Vec<PlayingCard>,
+    /// The base chips and mult of [`hand`](ScoreBreakdown::hand) at its level.
+    pub base: (N, N),
+    /// After every scored card (rank chips, enhancements, editions and
+    /// on‑scored Jokers).
+    pub after_cards: (N, N),
+    /// After every card held in hand (Steel and on‑held Jokers).
+    pub after_held: (N, N),
+    /// After the Joker phase; this is the final `(Chips, Mult)`.
+    pub after_jokers: (N, N),
+    /// The final score, `chips × mult` rounded down, exactly.
+    pub score: BigScore,
+    /// The scored cards destroyed once scoring finished (shattered Glass).
+    pub destroyed_cards: Vec<PlayingCard>,
+    /// What the round gives besides its score: Gold and Blue seals,
+    /// Gold cards held in hand and Lucky cards that won money.
+    pub rewards: Vec<CardReward>,
+}
+
+impl<N: Number> ScoreBreakdown<N> {
+    /// The same breakdown with every stage rounded to `f64`; the
+    /// [`score`](ScoreBreakdown::score) stays exact.
+    pub fn to_f64(&self) -> ScoreBreakdown {
+        let to_f64 = |(chips, mult): &(N, N)| (chips.to_f64(), mult.to_f64());
+        ScoreBreakdown {
+            hand: self.hand,
+            hand_level: self.hand_level,
+            scoring_cards: self.scoring_cards.clone(),
+            unscored_cards: self.unscored_cards.clone(),
+            base: to_f64(&self.base),
+            after_cards: to_f64(&self.after_cards),
+            after_held: to_f64(&self.after_held),
+            after_jokers: to_f64(&self.after_jokers),
+            score: self.score.clone(),
+            destroyed_cards: self.destroyed_cards.clone(),
+            rewards: self.rewards.clone(),
+        }
+    }
+}
+
+/// What a card gives besides chips and mult.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Reward {
+    /// Dollars earned.
+    Money(u32),
+    /// The Planet card of a poker hand.
+    Planet(PokerHand),
+}
+
+/// What gave a [`CardReward`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RewardCause {
+    Seal(Seal),
+    Enhancement(ExtraEnhancement),
+}
+
+impl Display for RewardCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RewardCause::Seal(seal) => write!(f, "{seal} seal"),
+            RewardCause::Enhancement(enhancement) => write!(f, "{enhancement}"),
+        }
+    }
+}
+
+/// The reward one card gave.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, PokerHand, Rank, Suit};
+/// use ortalab::poker::breakdown::{CardReward, Reward, RewardCause};
+/// use ortalab::poker::seals::Seal;
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let reward = CardReward {
+///     card: PlayingCard::from(Card::new(Rank::Two, Suit::Spades, None, None)),
+///     cause: RewardCause::Seal(Seal::Blue),
+///     reward: Reward::Planet(PokerHand::Flush),
+/// };
+/// assert_eq!(reward.to_string(), "Blue seal 2♠: Jupiter (Flush)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CardReward {
+    pub card: PlayingCard,
+    pub cause: RewardCause,
+    pub reward: Reward,
+}
+
+impl Display for CardReward {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Self {
+            card,
+            cause,
+            reward,
+        } = self;
+        write!(f, "{cause} {}{}: ", card.rank, card.suit)?;
+        match reward {
+            Reward::Money(dollars) => write!(f, "+${dollars}"),
+            Reward::Planet(hand) => write!(f, "{} ({hand})", planet(*hand)),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! # Card Notation
+//!
+//! Round and deck files write cards in `ortalib` notation (`K♠ Steel Foil`),
+//! extended with what `ortalib` lacks:
+//!
+//! - a Stone, Gold or Lucky [enhancement](crate::poker::enhancements) in the
+//!   enhancement's place (`A♥ Lucky Foil`), and
+//! - a [seal](crate::poker::seals) as the last word (`K♠ Steel Red`).
+//!
+//! `Gold` is both an enhancement and a seal. In the enhancement's place it
+//! is the enhancement, so `K♠ Gold` is a Gold card and `K♠ Gold Gold` a Gold
+//! card with a Gold seal; `K♠ Gold Seal` is a plain card with a Gold seal.
+
+use crate::poker::{enhancements::ExtraEnhancement, seals::Seal};
+use ortalib::Card;
+use serde::{Deserialize, Deserializer, de::Error};
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::Deref,
+};
+
+/// A card as Ortalab plays it: an `ortalib` [`Card`] with its seal and
+/// extra enhancement.
+///
+/// It dereferences to the [`Card`], so its rank, suit, enhancement and
+/// edition read the same as on a plain card. It prints in the notation it
+/// is parsed from.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Rank, Suit};
+/// use ortalab::poker::cards::{PlayingCard, parse_card};
+/// use ortalab::poker::seals::Seal;
+///
+/// let card = PlayingCard::from(Card::new(Rank::King, Suit::Spades, None, None));
+/// assert_eq!(card.rank, Rank::King);
+/// assert_eq!(card.seal, None);
+///
+/// let sealed = PlayingCard {
+///     seal: Some(Seal::Gold),
+///     ..card
+/// };
+/// assert_eq!(sealed.to_string(), "K♠ Gold Seal");
+/// assert_eq!(parse_card("A♥ Lucky Foil Red").unwrap().to_string(), "A♥ Lucky Foil Red");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlayingCard {
+    pub card: Card,
+    pub seal: Option<Seal>,
+    pub extra_enhancement: Option<ExtraEnhancement>,
+}
+
+impl PlayingCard {
+    /// Whether this is a Stone card, without rank or suit.
+    pub fn is_stone(&self) -> bool {
+        self.extra_enhancement == Some(ExtraEnhancement::Stone)
+    }
+}
+
+impl From<Card> for PlayingCard {
+    fn from(card: Card) -> Self {
+        Self {
+            card,
+            seal: None,
+            extra_enhancement: None,
+        }
+    }
+}
+
+impl Deref for PlayingCard {
+    type Target = Card;
+
+    fn deref(&self) -> &Card {
+        &self.card
+    }
+}
+
+impl Display for PlayingCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Card { rank, suit, .. } = self.card;
+        write!(f, "{rank}{suit}")?;
+        if let Some(enhancement) = self.card.enhancement {
+            write!(f, " {enhancement}")?;
+        }
+        if let Some(enhancement) = self.extra_enhancement {
+            write!(f, " {enhancement}")?;
+        }
+        if let Some(edition) = self.card.edition {
+            write!(f, " {edition}")?;
+        }
+        match self.seal {
+            Some(Seal::Gold) => write!(f, " Gold Seal"),
+            Some(seal) => write!(f, " {seal}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Debug for PlayingCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Parses a card in `ortalib` notation, with an optional extra enhancement
+/// and seal.
+///
+/// # Errors
+/// Returns the reason the card is invalid.
+///
+/// # Example
+/// ```
+/// use ortalib::{Edition, Enhancement, Rank};
+/// use ortalab::poker::cards::parse_card;
+/// use ortalab::poker::enhancements::ExtraEnhancement;
+/// use ortalab::poker::seals::Seal;
+///
+/// let card = parse_card("K♠ Steel Red").unwrap();
+/// assert_eq!(card.rank, Rank::King);
+/// assert_eq!(card.enhancement, Some(Enhancement::Steel));
+/// assert_eq!(card.seal, Some(Seal::Red));
+///
+/// let card = parse_card("A♥ Lucky Foil Gold").unwrap();
+/// assert_eq!(card.extra_enhancement, Some(ExtraEnhancement::Lucky));
+/// assert_eq!(card.edition, Some(Edition::Foil));
+/// assert_eq!(card.seal, Some(Seal::Gold));
+///
+/// let gold_card = parse_card("K♠ Gold").unwrap();
+/// assert_eq!(gold_card.extra_enhancement, Some(ExtraEnhancement::Gold));
+/// assert_eq!(gold_card.seal, None);
+///
+/// let gold_seal = parse_card("K♠ Gold Seal").unwrap();
+/// assert_eq!(gold_seal.extra_enhancement, None);
+/// assert_eq!(gold_seal.seal, Some(Seal::Gold));
+///
+/// assert_eq!(parse_card("K♠ Foil").unwrap().seal, None);
+/// assert!(parse_card("K♠ Steel Foil Red Gold").is_err());
+/// assert!(parse_card("K♠ Foil Seal").is_err());
+/// ```
+pub fn parse_card(s: &str) -> Result<PlayingCard, String> {
+    let mut words = s.split_whitespace().collect::<Vec<_>>();
+
+    // The seal is the last word, or the last but one before `Seal`
+    let seal = if words.last() == Some(&"Seal") && words.len() > 2 {
+        words.pop();
+        let seal = words.pop().unwrap_or_default();
+        Some(
+            seal.parse::<Seal>()
+                .map_err(|err| format!("Card `{s}`: {err}"))?,
+        )
+    } else {
+        let is_enhancement = |word: &str| word.parse::<ExtraEnhancement>().is_ok();
+        match words.as_slice() {
+            [_, last] if is_enhancement(last) => None,
+            [_, .., last] => {
+                let seal = last.parse::<Seal>().ok();
+                if seal.is_some() {
+                    words.pop();
+                }
+                seal
+            }
+            _ => None,
+        }
+    };
+
+    let enhancement = match words.get(1).map(|word| word.parse::<ExtraEnhancement>()) {
+        Some(Ok(enhancement)) => {
+            words.remove(1);
+            Some(enhancement)
+        }
+        _ => None,
+    };
+
+    let card = words.join(" ").parse::<Card>()?;
+    if enhancement.is_some() && card.enhancement.is_some() {
+        return Err(format!("Card `{s}` has two enhancements"));
+    }
+    Ok(PlayingCard {
+        card,
+        seal,
+        extra_enhancement: enhancement,
+    })
+}
+
+/// Deserializes a list of cards written in Ortalab's notation (see
+/// [`parse_card`]).
+pub fn deserialize_cards<'de, D>(deserializer: D) -> Result<Vec<PlayingCard>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|card| parse_card(card).map_err(D::Error::custom))
+        .collect()
+}
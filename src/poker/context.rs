@@ -0,0 +1,142 @@
+//! # Round Context
+//!
+//! Some Jokers depend on the state of the game rather than on the cards:
+//! money, hands and discards left, the cards left in the deck, and how often
+//! each poker hand has been played this run. A round file gives that state
+//! in an optional `context:` block:
+//!
+//! ```yaml
+//! context:
+//!   money: 12
+//!   hands_left: 1
+//!   discards_left: 0
+//!   cards_in_deck: 30
+//!   hands_played: {Flush: 3, Pair: 1}
+//! ```
+//!
+//! Every field is optional and defaults to the start of a round: $4, 4 hands,
+//! 3 discards, 44 cards in the deck and no hands played.
+
+use crate::poker::round::parse_poker_hand;
+use ortalib::PokerHand;
+use serde::{Deserialize, Deserializer, de::Error};
+use std::collections::HashMap;
+
+/// The game state a round is played in.
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::round::ScoringRound;
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [A♠]\ncontext:\n  money: 12\n  hands_played: {Flush: 3}",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(round.context.money, 12);
+/// assert_eq!(round.context.hands_left, 4);
+/// assert_eq!(round.context.times_played(PokerHand::Flush), 3);
+/// assert_eq!(round.context.times_played(PokerHand::Pair), 0);
+/// ```
+///
+/// The Jokers that read it, each scoring a High Card of 16 Chips x 1 Mult:
+/// ```
+/// use ortalib::{Card, PokerHand, Rank, Suit};
+/// use ortalab::poker::context::RoundContext;
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+/// use ortalab::poker::jokers::{JokerContext, JokerRegistry, joker_application};
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let start = RoundContext::default();
+/// let cases = [
+///     // +30 Chips for each discard left
+///     ("Banner", RoundContext { discards_left: 2, ..start.clone() }, (76.0, 1.0)),
+///     // +15 Mult with no discards left
+///     ("Mystic Summit", RoundContext { discards_left: 0, ..start.clone() }, (16.0, 16.0)),
+///     ("Mystic Summit", start.clone(), (16.0, 1.0)),
+///     // +2 Chips for each card left in the deck
+///     ("Blue Joker", RoundContext { cards_in_deck: 30, ..start.clone() }, (76.0, 1.0)),
+///     // +2 Chips for each $1, nothing while in debt
+///     ("Bull", RoundContext { money: 12, ..start.clone() }, (40.0, 1.0)),
+///     ("Bull", RoundContext { money: -5, ..start.clone() }, (16.0, 1.0)),
+///     // +1 Mult for each time the hand was played, including this one
+///     (
+///         "Supernova",
+///         RoundContext { hands_played: [(PokerHand::HighCard, 3)].into(), ..start.clone() },
+///         (16.0, 5.0),
+///     ),
+///     // x3 Mult on the final hand
+///     ("Acrobat", RoundContext { hands_left: 1, ..start.clone() }, (16.0, 3.0)),
+///     ("Acrobat", start.clone(), (16.0, 1.0)),
+/// ];
+///
+/// let scored = [PlayingCard::from(Card::new(Rank::Ace, Suit::Spades, None, None))];
+/// for (joker, round, expected) in cases {
+///     let jokers = [JokerSlot::new(JokerKind::custom(joker), None)];
+///     let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &scored, &[]).with_round(&round);
+///     let registry = JokerRegistry::builtin();
+///     let score = joker_application(registry, &ctx, 16.0, 1.0, &mut Explain::disabled());
+///     assert_eq!(score, expected, "{joker}");
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RoundContext {
+    /// The player's money in dollars (negative with Credit Card).
+    pub money: i64,
+
+    /// Hands left, counting the one being scored (as the game shows it
+    /// before the hand is played).
+    pub hands_left: u32,
+
+    /// Discards left.
+    pub discards_left: u32,
+
+    /// Cards left in the draw pile.
+    pub cards_in_deck: u32,
+
+    /// How often each poker hand was played this run, not counting the hand
+    /// being scored.
+    #[serde(deserialize_with = "deserialize_hands_played")]
+    pub hands_played: HashMap<PokerHand, u32>,
+}
+
+impl RoundContext {
+    /// How often `hand` was played this run before this hand.
+    pub fn times_played(&self, hand: PokerHand) -> u32 {
+        self.hands_played.get(&hand).copied().unwrap_or_default()
+    }
+
+    /// Whether the hand being scored is the last hand of the round.
+    pub fn is_final_hand(&self) -> bool {
+        self.hands_left <= 1
+    }
+}
+
+impl Default for RoundContext {
+    fn default() -> Self {
+        Self {
+            money: 4,
+            hands_left: 4,
+            discards_left: 3,
+            cards_in_deck: 44,
+            hands_played: HashMap::new(),
+        }
+    }
+}
+
+fn deserialize_hands_played<'de, D>(deserializer: D) -> Result<HashMap<PokerHand, u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, u32>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, count)| {
+            parse_poker_hand(&name)
+                .map(|hand| (hand, count))
+                .ok_or_else(|| D::Error::custom(format!("Invalid PokerHand `{name}`")))
+        })
+        .collect()
+}
@@ -0,0 +1,227 @@
+//! # Deck
+//!
+//! A [`Deck`] models the cards of a run in play: a draw pile, the hand, and a
+//! discard pile. It starts from the standard 52 cards or from a custom list
+//! of [`Card`]s (with enhancements and editions) loaded from YAML, and
+//! supports seeded shuffling, drawing up to a hand size, and playing or
+//! discarding cards from the hand.
+//!
+//! A deck file lists its cards, and may start from the standard deck:
+//!
+//! ```yaml
+//! standard: true      # start from the 52 standard cards (default: false)
+//! cards:              # extra cards
+//!   - A♠ Steel
+//!   - K♥ Glass Polychrome
+//! ```
+
+use crate::{
+    error::OrtalabError,
+    poker::{
+        cards::{PlayingCard, deserialize_cards},
+        rng::SeededRng,
+    },
+};
+use ortalib::{Card, Rank, Suit};
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// Every rank, from lowest to highest.
+const RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+/// Every suit.
+const SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+
+/// The hand size a player starts with.
+pub const DEFAULT_HAND_SIZE: usize = 8;
+
+/// The contents of a deck file.
+#[derive(Debug, Deserialize)]
+struct DeckFile {
+    #[serde(default)]
+    standard: bool,
+
+    #[serde(default, deserialize_with = "deserialize_cards")]
+    cards: Vec<PlayingCard>,
+}
+
+/// The cards of a run: draw pile, hand and discard pile.
+///
+/// The top of the draw pile is drawn first. Cards keep their identity as
+/// they move between piles, so a card can be played or discarded by passing
+/// it (or a copy of it) back.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::deck::Deck;
+/// use ortalab::poker::rng::SeededRng;
+///
+/// let mut deck = Deck::standard();
+/// deck.shuffle(&mut SeededRng::new(7));
+///
+/// deck.draw_to(8);
+/// assert_eq!(deck.hand().len(), 8);
+/// assert_eq!(deck.remaining(), 44);
+///
+/// // Discard two cards and draw back up to eight
+/// let discarded = deck.hand()[..2].to_vec();
+/// deck.discard(&discarded).unwrap();
+/// deck.draw_to(8);
+/// assert_eq!(deck.hand().len(), 8);
+/// assert_eq!(deck.discard_pile(), discarded.as_slice());
+/// assert_eq!(deck.remaining(), 42);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Deck {
+    draw_pile: Vec<PlayingCard>,
+    hand: Vec<PlayingCard>,
+    discard_pile: Vec<PlayingCard>,
+}
+
+impl Deck {
+    /// A deck whose draw pile is `cards`, the last one on top.
+    pub fn new(cards: Vec<PlayingCard>) -> Self {
+        Self {
+            draw_pile: cards,
+            hand: Vec::new(),
+            discard_pile: Vec::new(),
+        }
+    }
+
+    /// The standard 52‑card deck, unshuffled.
+    pub fn standard() -> Self {
+        Self::new(standard_cards())
+    }
+
+    /// Parses a deck file (see the module docs).
+    ///
+    /// # Errors
+    /// Returns [`OrtalabError::Yaml`] if `yaml` is not a valid deck.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalab::poker::deck::Deck;
+    ///
+    /// let deck = Deck::from_yaml("cards: [A♠ Steel, K♥ Glass Polychrome]").unwrap();
+    /// assert_eq!(deck.remaining(), 2);
+    ///
+    /// let deck = Deck::from_yaml("standard: true\ncards: [A♠ Steel]").unwrap();
+    /// assert_eq!(deck.remaining(), 53);
+    /// ```
+    pub fn from_yaml(yaml: &str) -> Result<Self, OrtalabError> {
+        let file: DeckFile = serde_yaml::from_str(yaml)?;
+        let mut cards = if file.standard {
+            standard_cards()
+        } else {
+            Vec::new()
+        };
+        cards.extend(file.cards);
+        Ok(Self::new(cards))
+    }
+
+    /// Reads a deck file (see the module docs).
+    ///
+    /// # Errors
+    /// Returns [`OrtalabError::Io`] if the file cannot be read, or
+    /// [`OrtalabError::Yaml`] if it is not a valid deck.
+    pub fn load(path: &Path) -> Result<Self, OrtalabError> {
+        let yaml = fs::read_to_string(path).map_err(|source| OrtalabError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_yaml(&yaml)
+    }
+
+    /// Shuffles the draw pile.
+    pub fn shuffle(&mut self, rng: &mut SeededRng) {
+        rng.shuffle(&mut self.draw_pile);
+    }
+
+    /// Draws from the top of the draw pile until the hand holds `hand_size`
+    /// cards or the draw pile runs out.
+    ///
+    /// # Returns
+    /// The number of cards drawn.
+    pub fn draw_to(&mut self, hand_size: usize) -> usize {
+        let wanted = hand_size.saturating_sub(self.hand.len());
+        let drawn = wanted.min(self.draw_pile.len());
+        let top = self.draw_pile.len() - drawn;
+        self.hand.extend(self.draw_pile.drain(top..).rev());
+        drawn
+    }
+
+    /// Moves `cards` from the hand to the discard pile.
+    ///
+    /// # Errors
+    /// Returns [`OrtalabError::InvalidRound`] if any card is not in the hand;
+    /// the hand is then left unchanged.
+    pub fn discard(&mut self, cards: &[PlayingCard]) -> Result<(), OrtalabError> {
+        let mut hand = self.hand.clone();
+        for card in cards {
+            let position = hand
+                .iter()
+                .position(|held| held == card)
+                .ok_or_else(|| OrtalabError::InvalidRound(format!("{card} is not in the hand")))?;
+            hand.remove(position);
+        }
+
+        self.hand = hand;
+        self.discard_pile.extend_from_slice(cards);
+        Ok(())
+    }
+
+    /// Discards `cards` from the hand, then draws back up to `hand_size`.
+    ///
+    /// # Errors
+    /// Same as [`Deck::discard`].
+    pub fn redraw(
+        &mut self,
+        cards: &[PlayingCard],
+        hand_size: usize,
+    ) -> Result<usize, OrtalabError> {
+        self.discard(cards)?;
+        Ok(self.draw_to(hand_size))
+    }
+
+    /// The cards in hand, in the order they were drawn.
+    pub fn hand(&self) -> &[PlayingCard] {
+        &self.hand
+    }
+
+    /// The cards left in the draw pile, bottom first.
+    pub fn draw_pile(&self) -> &[PlayingCard] {
+        &self.draw_pile
+    }
+
+    /// The discarded (and played) cards, in the order they left the hand.
+    pub fn discard_pile(&self) -> &[PlayingCard] {
+        &self.discard_pile
+    }
+
+    /// How many cards are left to draw.
+    pub fn remaining(&self) -> usize {
+        self.draw_pile.len()
+    }
+}
+
+/// One card of every rank and suit.
+fn standard_cards() -> Vec<PlayingCard> {
+    SUITS
+        .into_iter()
+        .flat_map(|suit| RANKS.map(|rank| Card::new(rank, suit, None, None).into()))
+        .collect()
+}
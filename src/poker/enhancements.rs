@@ -0,0 +1,78 @@
+//! # Extra Enhancements
+//!
+//! [`ortalib::Enhancement`] covers Bonus, Mult, Wild, Glass and Steel cards.
+//! Ortalab adds the other three as [`ExtraEnhancement`]s, written in the
+//! enhancement's place of a card (e.g. `K♠ Stone` or `A♥ Lucky Foil`):
+//!
+//! - **Stone**: **+50 Chips** instead of the rank's chips. A Stone card has
+//!   no rank or suit: it is never part of a poker hand, but always scores.
+//! - **Gold**: **$3** if held in hand at the end of the round.
+//! - **Lucky**: when scored, 1 in 5 chance for **+20 Mult** and 1 in 15
+//!   chance to win **$20**.
+//!
+//! Like [seals](crate::poker::seals), it is parsed into the card's
+//! [`PlayingCard::extra_enhancement`].
+
+use crate::poker::{
+    breakdown::{CardReward, Reward, RewardCause},
+    cards::PlayingCard,
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// An enhancement `ortalib` does not have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtraEnhancement {
+    Stone,
+    Gold,
+    Lucky,
+}
+
+impl Display for ExtraEnhancement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ExtraEnhancement::Stone => "Stone",
+            ExtraEnhancement::Gold => "Gold",
+            ExtraEnhancement::Lucky => "Lucky",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ExtraEnhancement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Stone" => Ok(ExtraEnhancement::Stone),
+            "Gold" => Ok(ExtraEnhancement::Gold),
+            "Lucky" => Ok(ExtraEnhancement::Lucky),
+            _ => Err(format!("Invalid Enhancement `{s}`")),
+        }
+    }
+}
+
+/// What the Gold cards held in hand give at the end of the round.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::cards::parse_card;
+/// use ortalab::poker::enhancements::gold_rewards;
+///
+/// let held = ["K♠ Gold", "2♥", "Q♦ Gold Seal"].map(|card| parse_card(card).unwrap());
+/// let rewards = gold_rewards(&held);
+/// assert_eq!(rewards.len(), 1);
+/// assert_eq!(rewards[0].to_string(), "Gold K♠: +$3");
+/// ```
+pub fn gold_rewards(held: &[PlayingCard]) -> Vec<CardReward> {
+    held.iter()
+        .filter(|card| card.extra_enhancement == Some(ExtraEnhancement::Gold))
+        .map(|&card| CardReward {
+            card,
+            cause: RewardCause::Enhancement(ExtraEnhancement::Gold),
+            reward: Reward::Money(3),
+        })
+        .collect()
+}
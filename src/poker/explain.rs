@@ -0,0 +1,89 @@
+//! # Explain
+//!
+//! This module records a step‑by‑step trace of the scoring pipeline. The
+//! trace is what the `--explain` flag prints: every step that changes the
+//! score, in the order it happened, together with the running chips × mult.
+//!
+//! Recording is opt‑in. A disabled [`Explain`] never formats a label, so the
+//! plain [`score`](crate::poker::score) path pays nothing for it.
+
+use crate::poker::number::Number;
+use ortalib::{Chips, Mult};
+use std::fmt::{self, Display, Formatter};
+
+/// A single recorded scoring step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainStep {
+    /// What happened in this step (e.g. `"Glass K♥: x2 Mult"`).
+    pub label: String,
+    /// Chips after this step.
+    pub chips: Chips,
+    /// Multiplier after this step.
+    pub mult: Mult,
+}
+
+/// Collects [`ExplainStep`]s while a round is being scored.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::explain::Explain;
+///
+/// let mut explain = Explain::new(true);
+/// explain.record(|| "Pair".to_string(), &(10.0, 2.0));
+/// explain.record(|| "K♥ +10 Chips".to_string(), &(20.0, 2.0));
+///
+/// assert_eq!(explain.steps().len(), 2);
+/// assert_eq!(explain.steps()[1].chips, 20.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Explain {
+    enabled: bool,
+    steps: Vec<ExplainStep>,
+}
+
+impl Explain {
+    /// Creates a new trace. When `enabled` is `false`, [`Explain::record`] is a no‑op.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Creates a trace that records nothing.
+    pub fn disabled() -> Self {
+        Self::new(false)
+    }
+
+    /// Whether this trace records steps.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Records a step with the running `(chips, mult)` after it.
+    ///
+    /// The label is only built when the trace is enabled.
+    pub fn record<N: Number>(&mut self, label: impl FnOnce() -> String, (chips, mult): &(N, N)) {
+        if self.enabled {
+            self.steps.push(ExplainStep {
+                label: label(),
+                chips: chips.to_f64(),
+                mult: mult.to_f64(),
+            });
+        }
+    }
+
+    /// The steps recorded so far, in order.
+    pub fn steps(&self) -> &[ExplainStep] {
+        &self.steps
+    }
+}
+
+impl Display for Explain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{:<45} {} x {}", step.label, step.chips, step.mult)?;
+        }
+        Ok(())
+    }
+}
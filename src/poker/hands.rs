@@ -7,14 +7,11 @@
 //! These helpers are used by the scoring pipeline to determine the
 //! strongest possible hand and compute its base chip/multiplier values.
 
-use crate::poker::helpers::compute_most_appear_suit;
+use crate::poker::{cards::PlayingCard, helpers::compute_most_appear_suit, joker_slot::JokerSlot};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
-use ortalib::{Card, Enhancement, Joker, JokerCard, PokerHand, Rank};
-use std::{
-    collections::{HashMap, HashSet},
-    ptr,
-};
+use ortalib::{Card, Enhancement, Joker, PokerHand, Rank, Suit};
+use std::{collections::HashSet, ptr};
 
 /// Detects a *High Card* hand.
 ///
@@ -27,7 +24,7 @@ use std::{
 /// * `cards` — The cards to evaluate.
 ///
 /// # Returns
-/// A vector containing the highest card.
+/// A vector containing the highest card, or an empty vector if `cards` is empty.
 ///
 /// # Example
 /// ```
@@ -42,15 +39,15 @@ use std::{
 /// let result = is_high_card(&cards);
 /// assert_eq!(result.len(), 1);
 /// assert_eq!(result[0].rank, Rank::Ace);
+/// assert!(is_high_card(&[]).is_empty());
 /// ```
 pub fn is_high_card(cards: &[Card]) -> Vec<Card> {
-    let card_to_return = cards
+    cards
         .iter()
         .max_by_key(|&card| OrderedFloat(card.rank.rank_value()))
         .copied()
-        .unwrap();
-    let vec_to_return = vec![card_to_return];
-    vec_to_return
+        .into_iter()
+        .collect()
 }
 
 /// Detects a *Pair* hand.
@@ -197,10 +194,19 @@ pub fn is_three_of_a_kind(cards: &[Card]) -> Vec<Card> {
 /// Detects a *Straight* hand.
 ///
 /// A Straight is five cards in consecutive rank order, not all of the same suit.
-/// Aces may be counted high or low.
+/// Aces may be counted high or low. With the *Shortcut* Joker, consecutive
+/// cards may skip one rank (e.g. 2 4 6 7 8).
 ///
 /// Base scoring: **30 chips × 4 mult**
 ///
+/// # Arguments
+/// * `cards` — The cards to evaluate, sorted by [`compute_card_order`].
+/// * `is_shortcut_exists` — Whether the *Shortcut* Joker allows gaps of one rank.
+///
+/// # Returns
+/// The cards of the longest run found (one card per rank). The caller decides
+/// whether the run is long enough (5 cards, or 4 with *Four Fingers*).
+///
 /// # Example
 /// ```
 /// use ortalib::{Card, Rank, Suit};
@@ -214,42 +220,101 @@ pub fn is_three_of_a_kind(cards: &[Card]) -> Vec<Card> {
 ///     Card::new(Rank::Nine, Suit::Clubs, None, None),
 /// ];
 ///
-/// let result = is_straight(&cards);
+/// let result = is_straight(&cards, false);
 /// assert_eq!(result.len(), 5);
+///
+/// // Shortcut: 2 4 6 7 8 is a straight, but only with the Joker
+/// let gapped = vec![
+///     Card::new(Rank::Two, Suit::Clubs, None, None),
+///     Card::new(Rank::Four, Suit::Hearts, None, None),
+///     Card::new(Rank::Six, Suit::Spades, None, None),
+///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
+///     Card::new(Rank::Eight, Suit::Clubs, None, None),
+/// ];
+/// assert_eq!(is_straight(&gapped, true).len(), 5);
+/// assert_eq!(is_straight(&gapped, false).len(), 3);
+///
+/// // Shortcut with a low Ace: A 3 4 5 7
+/// let ace_low = vec![
+///     Card::new(Rank::Three, Suit::Clubs, None, None),
+///     Card::new(Rank::Four, Suit::Hearts, None, None),
+///     Card::new(Rank::Five, Suit::Spades, None, None),
+///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
+///     Card::new(Rank::Ace, Suit::Clubs, None, None),
+/// ];
+/// assert_eq!(is_straight(&ace_low, true).len(), 5);
+///
+/// // A gap of two ranks is never allowed: 2 3 4 5 8
+/// let too_wide = vec![
+///     Card::new(Rank::Two, Suit::Clubs, None, None),
+///     Card::new(Rank::Three, Suit::Hearts, None, None),
+///     Card::new(Rank::Four, Suit::Spades, None, None),
+///     Card::new(Rank::Five, Suit::Diamonds, None, None),
+///     Card::new(Rank::Eight, Suit::Clubs, None, None),
+/// ];
+/// assert_eq!(is_straight(&too_wide, true).len(), 4);
 /// ```
-pub fn is_straight(cards: &[Card]) -> Vec<Card> {
-    let mut card_to_return: HashMap<Card, i32> = HashMap::new();
+pub fn is_straight(cards: &[Card], is_shortcut_exists: bool) -> Vec<Card> {
+    let max_step = if is_shortcut_exists { 2.0 } else { 1.0 };
 
-    // Check for consecutive cards with values from 2 - A
-    for (curr, next) in cards.iter().tuple_windows() {
-        let curr_order = compute_card_order(*curr);
-        let next_order = compute_card_order(*next);
-        if next_order - curr_order == 1.0 {
-            if !card_to_return.contains_key(curr) {
-                card_to_return.insert(*curr, 1);
-            }
+    // One card per rank, in ascending order with the Ace high
+    let ace_high = cards
+        .iter()
+        .map(|&card| (compute_card_order(card), card))
+        .dedup_by(|(a, _), (b, _)| a == b)
+        .collect_vec();
 
-            if !card_to_return.contains_key(next) {
-                card_to_return.insert(*next, 1);
+    // The same cards with the Ace counted low (below 2)
+    let ace_low = ace_high
+        .iter()
+        .map(|&(order, card)| {
+            if card.rank == Rank::Ace {
+                (1.0, card)
+            } else {
+                (order, card)
             }
+        })
+        .sorted_by_key(|&(order, _)| OrderedFloat(order))
+        .collect_vec();
+
+    [ace_high, ace_low]
+        .iter()
+        .map(|ordered| longest_run(ordered, max_step))
+        .max_by_key(|run| run.len())
+        .unwrap_or_default()
+}
+
+/// Finds the longest run of cards whose consecutive orders differ by at most `max_step`.
+fn longest_run(ordered: &[(f64, Card)], max_step: f64) -> Vec<Card> {
+    let mut best: Vec<Card> = Vec::new();
+    let mut current: Vec<Card> = Vec::new();
+    let mut prev_order = None;
+
+    for &(order, card) in ordered {
+        match prev_order {
+            Some(prev) if order - prev <= max_step => current.push(card),
+            _ => current = vec![card],
         }
-        // Handle case where Ace is the lowest value card (below 2)
-        else if next.rank == Rank::Ace && curr.rank == Rank::Five {
-            card_to_return.insert(*next, 1);
-        } else if next_order - curr_order != 1.0 {
-            card_to_return.clear();
+        if current.len() > best.len() {
+            best = current.clone();
         }
+        prev_order = Some(order);
     }
-    card_to_return.iter().map(|(&card, _)| card).collect_vec()
+    best
 }
 
 /// Detects a *Flush* hand.
 ///
 /// A Flush is five cards of any rank, all from the same suit.  
-/// Wild cards may substitute for missing suits.
+/// Wild cards may substitute for missing suits, and with *Smeared Joker*
+/// suits of the same colour count as one.
 ///
 /// Base scoring: **35 chips × 4 mult**
 ///
+/// # Arguments
+/// * `cards` — The cards to evaluate.
+/// * `is_smeared_exists` — Whether the *Smeared Joker* merges ♥/♦ and ♠/♣.
+///
 /// # Example
 /// ```
 /// use ortalib::{Card, Rank, Suit};
@@ -263,15 +328,28 @@ pub fn is_straight(cards: &[Card]) -> Vec<Card> {
 ///     Card::new(Rank::Two, Suit::Hearts, None, None),
 /// ];
 ///
-/// let result = is_flush(&cards);
+/// let result = is_flush(&cards, false);
 /// assert_eq!(result.len(), 5);
+///
+/// // Smeared Joker: 3♥ 7♦ 9♥ J♦ K♥
+/// let smeared = vec![
+///     Card::new(Rank::Three, Suit::Hearts, None, None),
+///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
+///     Card::new(Rank::Nine, Suit::Hearts, None, None),
+///     Card::new(Rank::Jack, Suit::Diamonds, None, None),
+///     Card::new(Rank::King, Suit::Hearts, None, None),
+/// ];
+/// assert_eq!(is_flush(&smeared, true).len(), 5);
+/// assert_eq!(is_flush(&smeared, false).len(), 3);
 /// ```
-pub fn is_flush(cards: &[Card]) -> Vec<Card> {
+pub fn is_flush(cards: &[Card], is_smeared_exists: bool) -> Vec<Card> {
     let mut card_to_return: Vec<Card> = Vec::new();
-    let base_suit = compute_most_appear_suit(cards);
+    let Some(base_suit) = compute_most_appear_suit(cards, is_smeared_exists) else {
+        return card_to_return;
+    };
 
     cards.iter().for_each(|card| {
-        if card.suit == base_suit {
+        if is_same_suit(card, base_suit, is_smeared_exists) {
             card_to_return.push(*card);
         } else if let Some(enhance) = card.enhancement
             && enhance == Enhancement::Wild
@@ -414,13 +492,15 @@ pub fn is_four_of_a_kind(cards: &[Card]) -> Vec<Card> {
 /// Detects a *Straight Flush* hand.
 ///
 /// A Straight Flush is five cards in consecutive rank order, all from the same suit.  
-/// Aces may be counted high or low.
+/// Aces may be counted high or low, and *Shortcut* allows gaps of one rank.
 ///
 /// Base scoring: **100 chips × 8 mult**
 ///
 /// # Arguments
 /// * `cards` — The cards to evaluate.
 /// * `is_four_finger_exists` — Whether a special joker/condition allows a 4‑card straight flush.
+/// * `is_shortcut_exists` — Whether the *Shortcut* Joker allows gaps of one rank.
+/// * `is_smeared_exists` — Whether the *Smeared Joker* merges ♥/♦ and ♠/♣.
 ///
 /// # Returns
 /// A vector containing the cards that form the straight flush.  
@@ -440,16 +520,53 @@ pub fn is_four_of_a_kind(cards: &[Card]) -> Vec<Card> {
 ///     Card::new(Rank::Nine, Suit::Hearts, None, None),
 /// ];
 ///
-/// let result = is_straight_flush(&cards, false);
+/// let result = is_straight_flush(&cards, false, false, false);
 ///
 /// // Should return exactly 5 cards (the straight flush)
 /// assert_eq!(result.len(), 5);
 /// assert!(result.iter().all(|c| c.suit == Suit::Hearts));
+///
+/// // Shortcut: 3♠ 5♠ 7♠ 9♠ J♠
+/// let gapped = vec![
+///     Card::new(Rank::Three, Suit::Spades, None, None),
+///     Card::new(Rank::Five, Suit::Spades, None, None),
+///     Card::new(Rank::Seven, Suit::Spades, None, None),
+///     Card::new(Rank::Nine, Suit::Spades, None, None),
+///     Card::new(Rank::Jack, Suit::Spades, None, None),
+/// ];
+/// assert_eq!(is_straight_flush(&gapped, false, true, false).len(), 5);
+/// assert!(is_straight_flush(&gapped, false, false, false).is_empty());
+///
+/// // Shortcut and Four Fingers: 4♦ 6♦ 7♦ 9♦ K♣
+/// let four_gapped = vec![
+///     Card::new(Rank::Four, Suit::Diamonds, None, None),
+///     Card::new(Rank::Six, Suit::Diamonds, None, None),
+///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
+///     Card::new(Rank::Nine, Suit::Diamonds, None, None),
+///     Card::new(Rank::King, Suit::Clubs, None, None),
+/// ];
+/// assert_eq!(is_straight_flush(&four_gapped, true, true, false).len(), 4);
+/// assert!(is_straight_flush(&four_gapped, true, false, false).is_empty());
+///
+/// // Smeared Joker: 8♠ 9♣ 10♠ J♣ Q♠
+/// let smeared = vec![
+///     Card::new(Rank::Eight, Suit::Spades, None, None),
+///     Card::new(Rank::Nine, Suit::Clubs, None, None),
+///     Card::new(Rank::Ten, Suit::Spades, None, None),
+///     Card::new(Rank::Jack, Suit::Clubs, None, None),
+///     Card::new(Rank::Queen, Suit::Spades, None, None),
+/// ];
+/// assert_eq!(is_straight_flush(&smeared, false, false, true).len(), 5);
+/// assert!(is_straight_flush(&smeared, false, false, false).is_empty());
 /// ```
-pub fn is_straight_flush(cards: &[Card], is_four_finger_exists: bool) -> Vec<Card> {
-    // let card_to_return = cards.clone();
-    let mut returned_card_from_is_flush = is_flush(cards);
-    let mut returned_card_from_is_straight = is_straight(cards);
+pub fn is_straight_flush(
+    cards: &[Card],
+    is_four_finger_exists: bool,
+    is_shortcut_exists: bool,
+    is_smeared_exists: bool,
+) -> Vec<Card> {
+    let mut returned_card_from_is_flush = is_flush(cards, is_smeared_exists);
+    let mut returned_card_from_is_straight = is_straight(cards, is_shortcut_exists);
 
     if returned_card_from_is_flush.len() == 5 && returned_card_from_is_straight.len() == 5 {
         return cards.to_vec();
@@ -527,6 +644,7 @@ pub fn is_five_of_a_kind(cards: &[Card]) -> Vec<Card> {
 ///
 /// # Arguments
 /// * `cards` — The cards to evaluate.
+/// * `is_smeared_exists` — Whether the *Smeared Joker* merges ♥/♦ and ♠/♣.
 ///
 /// # Returns
 /// A vector containing the five cards that form the flush house.  
@@ -546,24 +664,26 @@ pub fn is_five_of_a_kind(cards: &[Card]) -> Vec<Card> {
 ///     Card::new(Rank::Four, Suit::Hearts, None, None),
 /// ];
 ///
-/// let result = is_flush_house(&cards);
+/// let result = is_flush_house(&cards, false);
 ///
 /// assert_eq!(result.len(), 5);
 /// assert!(result.iter().any(|c| c.rank == Rank::Ten));
 /// assert!(result.iter().any(|c| c.rank == Rank::Four));
 /// assert!(result.iter().all(|c| c.suit == Suit::Hearts));
 /// ```
-pub fn is_flush_house(cards: &[Card]) -> Vec<Card> {
+pub fn is_flush_house(cards: &[Card], is_smeared_exists: bool) -> Vec<Card> {
     let mut card_to_return: Vec<Card> = Vec::new();
     let mut prev_rank = 0.0;
-    let base_suit = compute_most_appear_suit(cards);
+    let Some(base_suit) = compute_most_appear_suit(cards, is_smeared_exists) else {
+        return card_to_return;
+    };
 
     for (curr, next) in cards.iter().tuple_windows() {
         let curr_order = compute_card_order(*curr);
         let next_order = compute_card_order(*next);
 
         if prev_rank != 0.0 && curr_order == next_order && curr_order != prev_rank {
-            if curr.suit == base_suit {
+            if is_same_suit(curr, base_suit, is_smeared_exists) {
                 card_to_return.push(*curr);
             } else if let Some(enhance) = curr.enhancement
                 && enhance == Enhancement::Wild
@@ -573,7 +693,7 @@ pub fn is_flush_house(cards: &[Card]) -> Vec<Card> {
 
             if let Some(last) = cards.last()
                 && ptr::eq(next, last)
-                && next.suit == base_suit
+                && is_same_suit(next, base_suit, is_smeared_exists)
             {
                 card_to_return.push(*next);
             } else if let Some(enhance) = next.enhancement
@@ -586,14 +706,14 @@ pub fn is_flush_house(cards: &[Card]) -> Vec<Card> {
 
         if curr_order == next_order {
             prev_rank = curr_order;
-            if curr.suit == base_suit {
+            if is_same_suit(curr, base_suit, is_smeared_exists) {
                 card_to_return.push(*curr);
             } else if let Some(enhance) = curr.enhancement
                 && enhance == Enhancement::Wild
             {
                 card_to_return.push(*curr);
             }
-        } else if curr_order == prev_rank && curr.suit == base_suit {
+        } else if curr_order == prev_rank && is_same_suit(curr, base_suit, is_smeared_exists) {
             card_to_return.push(*curr);
         } else if let Some(enhance) = curr.enhancement
             && enhance == Enhancement::Wild
@@ -613,6 +733,7 @@ pub fn is_flush_house(cards: &[Card]) -> Vec<Card> {
 ///
 /// # Arguments
 /// * `cards` — The cards to evaluate.
+/// * `is_smeared_exists` — Whether the *Smeared Joker* merges ♥/♦ and ♠/♣.
 ///
 /// # Returns
 /// A vector containing the five cards that form the flush five.  
@@ -632,19 +753,22 @@ pub fn is_flush_house(cards: &[Card]) -> Vec<Card> {
 ///     Card::new(Rank::Ace, Suit::Hearts, None, None),
 /// ];
 ///
-/// let result = is_flush_five(&cards);
+/// let result = is_flush_five(&cards, false);
 ///
 /// assert_eq!(result.len(), 5);
 /// assert!(result.iter().all(|c| c.rank == Rank::Ace && c.suit == Suit::Hearts));
+/// assert!(is_flush_five(&[], false).is_empty());
 /// ```
-pub fn is_flush_five(cards: &[Card]) -> Vec<Card> {
+pub fn is_flush_five(cards: &[Card], is_smeared_exists: bool) -> Vec<Card> {
     let mut card_to_return: Vec<Card> = Vec::new();
-    let base_suit = cards.first().unwrap().suit;
+    let Some(base_suit) = cards.first().map(|card| card.suit) else {
+        return card_to_return;
+    };
 
     for (curr, next) in cards.iter().tuple_windows() {
         let curr_order = compute_card_order(*curr);
         let next_order = compute_card_order(*next);
-        if curr_order == next_order && curr.suit == base_suit {
+        if curr_order == next_order && is_same_suit(curr, base_suit, is_smeared_exists) {
             card_to_return.push(*curr);
             if let Some(last) = cards.last()
                 && ptr::eq(next, last)
@@ -656,6 +780,11 @@ pub fn is_flush_five(cards: &[Card]) -> Vec<Card> {
     card_to_return
 }
 
+/// Whether `card` is of `suit`, or of the same colour with Smeared Joker.
+fn is_same_suit(card: &Card, suit: Suit, is_smeared_exists: bool) -> bool {
+    card.suit == suit || (is_smeared_exists && card.suit.color() == suit.color())
+}
+
 pub fn compute_card_order(card: Card) -> f64 {
     match card.rank {
         Rank::Two => 2.0,
@@ -680,38 +809,94 @@ pub fn compute_card_order(card: Card) -> f64 {
 /// in descending order of strength (from Flush Five down to High Card).
 /// It returns the first matching hand along with the cards that form it.
 ///
+/// Stone cards have no rank or suit: they never help form a hand, but they
+/// are always among the cards that score.
+///
 /// # Arguments
 /// * `cards` — The cards to evaluate.
 /// * `jokers` — Any joker cards in play, which may affect hand detection.
 ///
 /// # Returns
-/// A tuple `(PokerHand, Vec<Card>)` where:
+/// A tuple `(PokerHand, Vec<PlayingCard>)` where:
 /// - `PokerHand` is the detected hand type.
-/// - `Vec<Card>` is the subset of cards that form the hand.
+/// - `Vec<PlayingCard>` is the subset of cards that form the hand.
 ///
 /// # Example
 /// ```
-/// use ortalib::{Card, Rank, Suit, JokerCard, PokerHand};
+/// use ortalib::{Card, Joker, Rank, Suit, PokerHand};
+/// use ortalab::poker::cards::PlayingCard;
+/// use ortalab::poker::joker_slot::JokerSlot;
 /// use ortalab::poker::hands::determine_poker_hand;
 ///
 /// // Construct a simple hand: Pair of Kings
-/// let cards = vec![
+/// let cards = [
 ///     Card::new(Rank::King, Suit::Hearts, None, None),
 ///     Card::new(Rank::King, Suit::Spades, None, None),
 ///     Card::new(Rank::Three, Suit::Clubs, None, None),
 ///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
 ///     Card::new(Rank::Nine, Suit::Hearts, None, None),
-/// ];
+/// ]
+/// .map(PlayingCard::from);
 ///
-/// let jokers: Vec<JokerCard> = vec![];
+/// let jokers: Vec<JokerSlot> = vec![];
 ///
 /// let (hand, selected) = determine_poker_hand(&cards, &jokers);
 ///
 /// assert_eq!(hand, PokerHand::Pair);
 /// assert_eq!(selected.len(), 2);
 /// assert!(selected.iter().all(|c| c.rank == Rank::King));
+///
+/// // Shortcut and Four Fingers together: 2 4 6 7 is a gapped 4‑card straight
+/// let gapped = [
+///     Card::new(Rank::Two, Suit::Clubs, None, None),
+///     Card::new(Rank::Four, Suit::Hearts, None, None),
+///     Card::new(Rank::Six, Suit::Spades, None, None),
+///     Card::new(Rank::Seven, Suit::Diamonds, None, None),
+///     Card::new(Rank::King, Suit::Clubs, None, None),
+/// ]
+/// .map(PlayingCard::from);
+/// let jokers = vec![
+///     JokerSlot::new(Joker::Shortcut, None),
+///     JokerSlot::new(Joker::FourFingers, None),
+/// ];
+/// let (hand, selected) = determine_poker_hand(&gapped, &jokers);
+/// assert_eq!(hand, PokerHand::Straight);
+/// assert_eq!(selected.len(), 4);
+///
+/// let (hand, _) = determine_poker_hand(&gapped, &jokers[..1]);
+/// assert_eq!(hand, PokerHand::HighCard);
+///
+/// // A Stone card breaks the flush, but still scores
+/// use ortalab::poker::cards::parse_card;
+/// let hearts = ["2♥", "5♥", "9♥", "J♥", "K♥ Stone"].map(|card| parse_card(card).unwrap());
+/// let (hand, selected) = determine_poker_hand(&hearts, &[]);
+/// assert_eq!(hand, PokerHand::HighCard);
+/// assert_eq!(selected, [hearts[3], hearts[4]]);
 /// ```
-pub fn determine_poker_hand(cards: &[Card], jokers: &[JokerCard]) -> (PokerHand, Vec<Card>) {
+pub fn determine_poker_hand(
+    cards: &[PlayingCard],
+    jokers: &[JokerSlot],
+) -> (PokerHand, Vec<PlayingCard>) {
+    let (stones, cards): (Vec<PlayingCard>, Vec<PlayingCard>) =
+        cards.iter().partition(|card| card.is_stone());
+    let plain_cards = cards.iter().map(|card| card.card).collect_vec();
+    let (hand, return_card) = match plain_cards.is_empty() {
+        true => (PokerHand::HighCard, Vec::new()),
+        false => detect_poker_hand(&plain_cards, jokers),
+    };
+
+    // Every card is unique, so each one found maps back to its playing card
+    let mut return_card = return_card
+        .iter()
+        .filter_map(|card| cards.iter().find(|playing| playing.card == *card))
+        .copied()
+        .collect_vec();
+    return_card.extend(stones);
+    (hand, return_card)
+}
+
+/// Finds the strongest poker hand among cards that all have a rank and suit.
+fn detect_poker_hand(cards: &[Card], jokers: &[JokerSlot]) -> (PokerHand, Vec<Card>) {
     let mut return_card;
     let sorted_cards_played: Vec<_> = cards
         .iter()
@@ -719,15 +904,17 @@ pub fn determine_poker_hand(cards: &[Card], jokers: &[JokerCard]) -> (PokerHand,
         .copied()
         .collect();
     let is_four_finger_exists = jokers.iter().any(|card| card.joker == Joker::FourFingers);
+    let is_shortcut_exists = jokers.iter().any(|card| card.joker == Joker::Shortcut);
+    let is_smeared_exists = jokers.iter().any(|card| card.joker == Joker::SmearedJoker);
 
     // Check if a flush five  exists
-    return_card = is_flush_five(&sorted_cards_played);
+    return_card = is_flush_five(&sorted_cards_played, is_smeared_exists);
     if return_card.len() == 5 || (return_card.len() == 4 && is_four_finger_exists) {
         return (PokerHand::FlushFive, cards.to_vec());
     }
 
     // Check if a flush house  exists
-    return_card = is_flush_house(&sorted_cards_played);
+    return_card = is_flush_house(&sorted_cards_played, is_smeared_exists);
     if return_card.len() == 5 {
         return (PokerHand::FlushHouse, cards.to_vec());
     }
@@ -739,8 +926,12 @@ pub fn determine_poker_hand(cards: &[Card], jokers: &[JokerCard]) -> (PokerHand,
     }
 
     // Check if a straight flush exists
-    return_card = is_straight_flush(&sorted_cards_played, is_four_finger_exists);
-    // println!("return: {:?}", return_card);
+    return_card = is_straight_flush(
+        &sorted_cards_played,
+        is_four_finger_exists,
+        is_shortcut_exists,
+        is_smeared_exists,
+    );
     if return_card.len() == 5 {
         return (PokerHand::StraightFlush, cards.to_vec());
     } else if return_card.len() == 4 && is_four_finger_exists {
@@ -760,13 +951,13 @@ pub fn determine_poker_hand(cards: &[Card], jokers: &[JokerCard]) -> (PokerHand,
     }
 
     // Check if a flush exists
-    return_card = is_flush(&sorted_cards_played);
+    return_card = is_flush(&sorted_cards_played, is_smeared_exists);
     if return_card.len() == 5 || (return_card.len() == 4 && is_four_finger_exists) {
         return (PokerHand::Flush, cards.to_vec());
     }
 
     // Check if a straight exists
-    return_card = is_straight(&sorted_cards_played);
+    return_card = is_straight(&sorted_cards_played, is_shortcut_exists);
     if return_card.len() == 5 {
         return (PokerHand::Straight, cards.to_vec());
     } else if return_card.len() == 4 && is_four_finger_exists {
@@ -10,15 +10,15 @@ use ortalib::{Card, Enhancement, Suit, SuitColor};
 /// Computes the most frequently appearing suit among the given cards.
 ///
 /// This is typically used for flush‑based hands to determine the dominant suit.
+/// With *Smeared Joker*, suits are counted by colour: the result is the most
+/// common suit of the colour that appears most often.
 ///
 /// # Arguments
 /// * `cards` ‑ A slice of [`Card`]s to analyze.
+/// * `is_smeared_exists` ‑ Whether the *Smeared Joker* merges ♥/♦ and ♠/♣.
 ///
 /// # Returns
-/// The [`Suit`] that appears most often.
-///
-/// # Panics
-/// Panics if `cards` is empty.
+/// The [`Suit`] that appears most often, or `None` if `cards` is empty.
 ///
 /// # Example
 /// ```
@@ -31,16 +31,39 @@ use ortalib::{Card, Enhancement, Suit, SuitColor};
 ///     Card::new(Rank::Two, Suit::Clubs, None, None),
 /// ];
 ///
-/// assert_eq!(compute_most_appear_suit(&cards), Suit::Hearts);
+/// assert_eq!(compute_most_appear_suit(&cards, false), Some(Suit::Hearts));
+/// assert_eq!(compute_most_appear_suit(&[], false), None);
+///
+/// // Two Spades outnumber one Heart, but not a Heart and two Diamonds
+/// let cards = vec![
+///     Card::new(Rank::Ace, Suit::Spades, None, None),
+///     Card::new(Rank::King, Suit::Spades, None, None),
+///     Card::new(Rank::Queen, Suit::Hearts, None, None),
+///     Card::new(Rank::Two, Suit::Diamonds, None, None),
+///     Card::new(Rank::Three, Suit::Diamonds, None, None),
+/// ];
+/// assert_eq!(compute_most_appear_suit(&cards, true), Some(Suit::Diamonds));
 /// ```
-pub fn compute_most_appear_suit(cards: &[Card]) -> Suit {
+pub fn compute_most_appear_suit(cards: &[Card], is_smeared_exists: bool) -> Option<Suit> {
+    let color = match is_smeared_exists {
+        true => Some(
+            cards
+                .iter()
+                .counts_by(|c| c.suit.color())
+                .into_iter()
+                .max_by_key(|(_, count)| *count)?
+                .0,
+        ),
+        false => None,
+    };
+
     cards
         .iter()
+        .filter(|c| color.is_none_or(|color| c.suit.color() == color))
         .counts_by(|c| c.suit)
         .into_iter()
         .max_by_key(|(_, count)| *count)
         .map(|(suit, _)| suit)
-        .unwrap()
 }
 
 /// Determines whether the given suit is present among the scored cards,
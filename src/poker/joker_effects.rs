@@ -0,0 +1,597 @@
+//! # Joker Effects
+//!
+//! The [`JokerEffect`] implementations of every built‑in Joker, and
+//! [`register_standard_jokers`], which adds them all to a [`JokerRegistry`].
+//!
+//! Jokers that only change the rules (e.g. Four Fingers, Splash, Mime) have no
+//! effect of their own and are not registered; the hand detectors and the
+//! retrigger logic look for them directly.
+//!
+//! Ortalab's own Jokers are not in `ortalib`, so they are named like any
+//! house‑rule Joker ([`JokerKind::Custom`]) and registered under the names
+//! below. The rule‑changing ones among them (Brainstorm, Space Joker,
+//! Oops! All 6s) are registered as [`SpecialJoker`]s, so their names are
+//! known to the registry.
+
+use crate::poker::{
+    cards::PlayingCard,
+    compute_card_order, determine_current_suit, determine_total_colors,
+    joker_slot::JokerKind,
+    jokers::{Activation, JokerContext, JokerEffect, JokerRegistry},
+    number::Number,
+};
+use ordered_float::OrderedFloat;
+use ortalib::{Chips, Joker, Mult, PokerHand, Rank, Suit, SuitColor};
+
+/// Hands that contain a Pair.
+const PAIR_HANDS: &[PokerHand] = &[
+    PokerHand::Pair,
+    PokerHand::TwoPair,
+    PokerHand::FullHouse,
+    PokerHand::ThreeOfAKind,
+    PokerHand::FourOfAKind,
+    PokerHand::FiveOfAKind,
+    PokerHand::FlushHouse,
+    PokerHand::FlushFive,
+];
+
+/// Hands that contain a Three of a Kind.
+const THREE_OF_A_KIND_HANDS: &[PokerHand] = &[
+    PokerHand::ThreeOfAKind,
+    PokerHand::FullHouse,
+    PokerHand::FlushHouse,
+    PokerHand::FourOfAKind,
+    PokerHand::FiveOfAKind,
+    PokerHand::FlushFive,
+];
+
+/// Hands that contain a Two Pair.
+const TWO_PAIR_HANDS: &[PokerHand] = &[
+    PokerHand::TwoPair,
+    PokerHand::FullHouse,
+    PokerHand::FlushHouse,
+];
+
+/// Hands that contain a Straight.
+const STRAIGHT_HANDS: &[PokerHand] = &[PokerHand::Straight, PokerHand::StraightFlush];
+
+/// Hands that contain a Flush.
+const FLUSH_HANDS: &[PokerHand] = &[
+    PokerHand::Flush,
+    PokerHand::FlushFive,
+    PokerHand::FlushHouse,
+    PokerHand::FourOfAKind,
+];
+
+/// Copies the ability of the leftmost Joker (see
+/// [`resolution`](crate::poker::resolution)).
+pub const BRAINSTORM: &str = "Brainstorm";
+
+/// See [`Banner`].
+pub const BANNER: &str = "Banner";
+
+/// See [`MysticSummit`].
+pub const MYSTIC_SUMMIT: &str = "Mystic Summit";
+
+/// See [`BlueJoker`].
+pub const BLUE_JOKER: &str = "Blue Joker";
+
+/// See [`Bull`].
+pub const BULL: &str = "Bull";
+
+/// See [`Supernova`].
+pub const SUPERNOVA: &str = "Supernova";
+
+/// See [`Acrobat`].
+pub const ACROBAT: &str = "Acrobat";
+
+/// See [`Bloodstone`].
+pub const BLOODSTONE: &str = "Bloodstone";
+
+/// 1 in 4 chance to upgrade the level of the played poker hand before it
+/// scores.
+pub const SPACE_JOKER: &str = "Space Joker";
+
+/// Doubles the odds of every chance (e.g. 1 in 4 becomes 2 in 4).
+pub const OOPS_ALL_SIXES: &str = "Oops! All 6s";
+
+/// Registers the effect of every built‑in Joker.
+///
+/// # Example
+/// ```
+/// use ortalib::Joker;
+/// use ortalab::poker::joker_effects::register_standard_jokers;
+/// use ortalab::poker::jokers::{Activation, JokerRegistry};
+///
+/// let mut registry: JokerRegistry = JokerRegistry::new();
+/// register_standard_jokers(&mut registry);
+///
+/// assert_eq!(registry.get(&Joker::Baron.into()).unwrap().activation(), Activation::OnHeld);
+/// assert!(registry.get(&Joker::Splash.into()).is_none());
+/// ```
+pub fn register_standard_jokers<N: Number>(registry: &mut JokerRegistry<N>) {
+    registry
+        .register(Joker::Joker, BaseJoker)
+        .register(Joker::JollyJoker, HandJoker::mult(PAIR_HANDS, 8.0))
+        .register(Joker::SlyJoker, HandJoker::chips(PAIR_HANDS, 50.0))
+        .register(
+            Joker::ZanyJoker,
+            HandJoker::mult(THREE_OF_A_KIND_HANDS, 12.0),
+        )
+        .register(
+            Joker::WilyJoker,
+            HandJoker::chips(THREE_OF_A_KIND_HANDS, 100.0),
+        )
+        .register(Joker::MadJoker, HandJoker::mult(TWO_PAIR_HANDS, 10.0))
+        .register(Joker::CleverJoker, HandJoker::chips(TWO_PAIR_HANDS, 80.0))
+        .register(Joker::CrazyJoker, HandJoker::mult(STRAIGHT_HANDS, 12.0))
+        .register(Joker::DeviousJoker, HandJoker::chips(STRAIGHT_HANDS, 100.0))
+        .register(Joker::DrollJoker, HandJoker::mult(FLUSH_HANDS, 10.0))
+        .register(Joker::CraftyJoker, HandJoker::chips(FLUSH_HANDS, 80.0))
+        .register(Joker::AbstractJoker, AbstractJoker)
+        .register(Joker::Blackboard, Blackboard)
+        .register(Joker::FlowerPot, FlowerPot)
+        .register(Joker::GreedyJoker, SuitJoker(Suit::Diamonds))
+        .register(Joker::LustyJoker, SuitJoker(Suit::Hearts))
+        .register(Joker::WrathfulJoker, SuitJoker(Suit::Spades))
+        .register(Joker::GluttonousJoker, SuitJoker(Suit::Clubs))
+        .register(Joker::Fibonacci, Fibonacci)
+        .register(Joker::ScaryFace, ScaryFace)
+        .register(Joker::EvenSteven, EvenSteven)
+        .register(Joker::OddTodd, OddTodd)
+        .register(Joker::Photograph, Photograph)
+        .register(Joker::SmileyFace, SmileyFace)
+        .register(Joker::RaisedFist, RaisedFist)
+        .register(Joker::Baron, Baron)
+        .register(JokerKind::custom(BANNER), Banner)
+        .register(JokerKind::custom(MYSTIC_SUMMIT), MysticSummit)
+        .register(JokerKind::custom(BLUE_JOKER), BlueJoker)
+        .register(JokerKind::custom(BULL), Bull)
+        .register(JokerKind::custom(SUPERNOVA), Supernova)
+        .register(JokerKind::custom(ACROBAT), Acrobat)
+        .register(JokerKind::custom(BLOODSTONE), Bloodstone)
+        .register(JokerKind::custom(BRAINSTORM), SpecialJoker)
+        .register(JokerKind::custom(SPACE_JOKER), SpecialJoker)
+        .register(JokerKind::custom(OOPS_ALL_SIXES), SpecialJoker);
+}
+
+/// A Joker the pipeline looks for directly instead of dispatching to it
+/// (Brainstorm, Space Joker, Oops! All 6s). It never fires.
+pub struct SpecialJoker;
+
+impl<N: Number> JokerEffect<N> for SpecialJoker {
+    fn activation(&self) -> Activation {
+        Activation::Special
+    }
+}
+
+/// **+4 Mult**.
+pub struct BaseJoker;
+
+impl<N: Number> JokerEffect<N> for BaseJoker {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, _: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip, mul + 4.0)
+    }
+}
+
+/// **+Chips** and/or **+Mult** when the played hand is one of `hands`.
+///
+/// Covers the Jolly, Zany, Mad, Crazy, Droll, Sly, Wily,
+/// Clever, Devious and Crafty Jokers.
+pub struct HandJoker {
+    pub hands: &'static [PokerHand],
+    pub chips: Chips,
+    pub mult: Mult,
+}
+
+impl HandJoker {
+    pub const fn mult(hands: &'static [PokerHand], mult: Mult) -> Self {
+        Self {
+            hands,
+            chips: 0.0,
+            mult,
+        }
+    }
+
+    pub const fn chips(hands: &'static [PokerHand], chips: Chips) -> Self {
+        Self {
+            hands,
+            chips,
+            mult: 0.0,
+        }
+    }
+}
+
+impl<N: Number> JokerEffect<N> for HandJoker {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        if self.hands.contains(&ctx.hand) {
+            (chip + self.chips, mul + self.mult)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+3 Mult** for each Joker card.
+pub struct AbstractJoker;
+
+impl<N: Number> JokerEffect<N> for AbstractJoker {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip, mul + 3.0 * ctx.jokers.len() as f64)
+    }
+}
+
+/// **x3 Mult** if all cards held in hand are ♠Spades or ♣Clubs (Wild cards count).
+pub struct Blackboard;
+
+impl<N: Number> JokerEffect<N> for Blackboard {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        let all_black = ctx
+            .held_cards
+            .iter()
+            .all(|card| ctx.is_suit(card, Suit::Spades) || ctx.is_suit(card, Suit::Clubs));
+
+        if all_black {
+            (chip, mul * 3.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **x3 Mult** if the scored cards contain a ♦Diamond, ♣Club, ♥Heart and ♠Spade
+/// (or two cards of each colour with Smeared Joker).
+pub struct FlowerPot;
+
+impl<N: Number> JokerEffect<N> for FlowerPot {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        // Stone cards have no suit
+        let on_scored = &ctx
+            .scored_cards
+            .iter()
+            .filter(|card| !card.is_stone())
+            .map(|card| card.card)
+            .collect::<Vec<_>>();
+        if on_scored.len() < 4 {
+            return (chip, mul);
+        }
+
+        let having_all_suits = [Suit::Diamonds, Suit::Hearts, Suit::Spades, Suit::Clubs]
+            .into_iter()
+            .all(|suit| determine_current_suit(on_scored, suit));
+        let having_both_colors = determine_total_colors(on_scored, SuitColor::Red)
+            && determine_total_colors(on_scored, SuitColor::Black);
+
+        if having_all_suits || (ctx.is_smeared_exists && having_both_colors) {
+            (chip, mul * 3.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+3 Mult** for each scored card of `suit` (Greedy, Lusty, Wrathful and
+/// Gluttonous Jokers). Wild cards always count; with Smeared Joker the other
+/// suit of the same colour counts too.
+pub struct SuitJoker(pub Suit);
+
+impl<N: Number> JokerEffect<N> for SuitJoker {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.is_suit(card, self.0) {
+            (chip, mul + 3.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+8 Mult** for each scored Ace, 2, 3, 5 or 8.
+pub struct Fibonacci;
+
+impl<N: Number> JokerEffect<N> for Fibonacci {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        let fibonacci = [Rank::Ace, Rank::Two, Rank::Three, Rank::Five, Rank::Eight];
+        if ctx.rank(card).is_some_and(|rank| fibonacci.contains(&rank)) {
+            (chip, mul + 8.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+30 Chips** for each scored face card.
+pub struct ScaryFace;
+
+impl<N: Number> JokerEffect<N> for ScaryFace {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.is_face(card) {
+            (chip + 30.0, mul)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+4 Mult** for each scored card of even rank (10, 8, 6, 4, 2).
+pub struct EvenSteven;
+
+impl<N: Number> JokerEffect<N> for EvenSteven {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        let value = compute_card_order(card.card);
+        if ctx.rank(card).is_some() && value <= 10.0 && value % 2.0 == 0.0 {
+            (chip, mul + 4.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+31 Chips** for each scored card of odd rank (A, 9, 7, 5, 3).
+pub struct OddTodd;
+
+impl<N: Number> JokerEffect<N> for OddTodd {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        let value = compute_card_order(card.card);
+        let is_odd = value == 14.0 || (value < 10.0 && value % 2.0 != 0.0);
+        if ctx.rank(card).is_some() && is_odd {
+            (chip + 31.0, mul)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **x2 Mult** when the first scored face card is scored (on every trigger of it).
+pub struct Photograph;
+
+impl<N: Number> JokerEffect<N> for Photograph {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        let first_face = ctx.scored_cards.iter().find(|c| ctx.is_face(c));
+        if first_face == Some(card) {
+            (chip, mul * 2.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+5 Mult** for each scored face card.
+pub struct SmileyFace;
+
+impl<N: Number> JokerEffect<N> for SmileyFace {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.is_face(card) {
+            (chip, mul + 5.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// Adds double the rank value of the lowest ranked card held in hand to Mult
+/// (the rightmost one on ties).
+pub struct RaisedFist;
+
+impl<N: Number> JokerEffect<N> for RaisedFist {
+    fn activation(&self) -> Activation {
+        Activation::OnHeld
+    }
+
+    fn on_held(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        let lowest_card = ctx
+            .held_cards
+            .iter()
+            .rev()
+            .filter(|c| ctx.rank(c).is_some())
+            .min_by_key(|&c| OrderedFloat(c.rank.rank_value()));
+
+        if lowest_card == Some(card) {
+            (chip, mul + card.rank.rank_value() * 2.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **x1.5 Mult** for each King held in hand.
+pub struct Baron;
+
+impl<N: Number> JokerEffect<N> for Baron {
+    fn activation(&self) -> Activation {
+        Activation::OnHeld
+    }
+
+    fn on_held(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.rank(card) == Some(Rank::King) {
+            (chip, mul * 1.5)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+30 Chips** for each remaining discard.
+pub struct Banner;
+
+impl<N: Number> JokerEffect<N> for Banner {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip + 30.0 * ctx.round.discards_left as f64, mul)
+    }
+}
+
+/// **+15 Mult** when 0 discards remain.
+pub struct MysticSummit;
+
+impl<N: Number> JokerEffect<N> for MysticSummit {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.round.discards_left == 0 {
+            (chip, mul + 15.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// **+2 Chips** for each card remaining in the deck.
+pub struct BlueJoker;
+
+impl<N: Number> JokerEffect<N> for BlueJoker {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip + 2.0 * ctx.round.cards_in_deck as f64, mul)
+    }
+}
+
+/// **+2 Chips** for each $1 the player has (nothing while in debt).
+pub struct Bull;
+
+impl<N: Number> JokerEffect<N> for Bull {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip + 2.0 * ctx.round.money.max(0) as f64, mul)
+    }
+}
+
+/// Adds the number of times the poker hand has been played this run,
+/// including this hand, to Mult.
+pub struct Supernova;
+
+impl<N: Number> JokerEffect<N> for Supernova {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        (chip, mul + (ctx.round.times_played(ctx.hand) + 1) as f64)
+    }
+}
+
+/// **x3 Mult** on the final hand of the round.
+pub struct Acrobat;
+
+impl<N: Number> JokerEffect<N> for Acrobat {
+    fn activation(&self) -> Activation {
+        Activation::Independent
+    }
+
+    fn independent(&self, ctx: &JokerContext, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.round.is_final_hand() {
+            (chip, mul * 3.0)
+        } else {
+            (chip, mul)
+        }
+    }
+}
+
+/// 1 in 2 chance for each scored ♥Heart card to give **x1.5 Mult**.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, PokerHand, Rank, Suit};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+/// use ortalab::poker::jokers::{JokerContext, JokerRegistry, apply_scored_card_jokers};
+/// use ortalab::poker::luck::Luck;
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let heart = PlayingCard::from(Card::new(Rank::Ace, Suit::Hearts, None, None));
+/// let scored = [heart];
+/// let registry = JokerRegistry::builtin();
+/// let apply = |jokers: &[JokerSlot], luck: &Luck| {
+///     let ctx = JokerContext::new(PokerHand::HighCard, jokers, &scored, &[]).with_luck(luck);
+///     apply_scored_card_jokers(registry, &ctx, &heart, 16.0, 2.0, &mut Explain::disabled())
+/// };
+///
+/// // Enumerated, the first outcome is a miss and the second a hit
+/// let jokers = [JokerSlot::new(JokerKind::custom("Bloodstone"), None)];
+/// let luck = Luck::enumerating();
+/// assert_eq!(apply(&jokers, &luck), (16.0, 2.0));
+/// assert!(luck.next_outcome());
+/// assert_eq!(apply(&jokers, &luck), (16.0, 3.0));
+/// assert_eq!(luck.probability(), 0.5);
+/// assert!(!luck.next_outcome());
+///
+/// // Oops! All 6s makes it certain
+/// let jokers = [
+///     JokerSlot::new(JokerKind::custom("Oops! All 6s"), None),
+///     JokerSlot::new(JokerKind::custom("Bloodstone"), None),
+/// ];
+/// assert_eq!(apply(&jokers, &Luck::enumerating()), (16.0, 3.0));
+/// ```
+pub struct Bloodstone;
+
+impl<N: Number> JokerEffect<N> for Bloodstone {
+    fn activation(&self) -> Activation {
+        Activation::OnScored
+    }
+
+    fn on_scored(&self, ctx: &JokerContext, card: &PlayingCard, (chip, mul): (N, N)) -> (N, N) {
+        if ctx.is_suit(card, Suit::Hearts) && ctx.roll(1, 2) {
+            (chip, mul * 1.5)
+        } else {
+            (chip, mul)
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! # Joker Slots
+//!
+//! [`ortalib::Joker`] only covers the Jokers shipped with `ortalib`. This
+//! module wraps it in [`JokerKind`], which also names every other Joker
+//! (Ortalab's own, e.g. **Brainstorm** or **Banner**, and house‑rule Jokers),
+//! and [`JokerSlot`], a Joker sitting in one of the player's Joker slots
+//! together with its edition.
+//!
+//! Both types parse from the same notation as [`JokerCard`]
+//! (e.g. `Brainstorm Polychrome`), so round files can mix them freely. A name
+//! `ortalib` does not know parses as a [`JokerKind::Custom`]; whether such a
+//! Joker exists is up to the [`JokerRegistry`](crate::poker::jokers::JokerRegistry)
+//! it is scored with.
+
+use ortalib::{Edition, Joker, JokerCard};
+use serde::{Deserialize, Deserializer, de::Visitor};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Every Joker Ortalab can score: the `ortalib` Jokers plus any other Joker
+/// known by name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JokerKind {
+    /// A Joker defined by `ortalib`.
+    Standard(Joker),
+
+    /// A Joker `ortalib` does not define, identified by name: one of
+    /// Ortalab's own (see [`joker_effects`](crate::poker::joker_effects)) or a
+    /// house‑rule Joker. Its effect must be registered in a
+    /// [`JokerRegistry`](crate::poker::jokers::JokerRegistry).
+    Custom(String),
+}
+
+impl JokerKind {
+    /// The Joker `ortalib` does not define named `name`.
+    pub fn custom(name: impl Into<String>) -> Self {
+        JokerKind::Custom(name.into())
+    }
+}
+
+/// One Joker in the player's Joker slots.
+///
+/// # Example
+/// ```
+/// use ortalib::{Edition, Joker};
+/// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+///
+/// let slot: JokerSlot = "Brainstorm Foil".parse().unwrap();
+/// assert_eq!(slot.joker, JokerKind::custom("Brainstorm"));
+/// assert_eq!(slot.edition, Some(Edition::Foil));
+///
+/// let slot: JokerSlot = "Baron".parse().unwrap();
+/// assert_eq!(slot.joker, Joker::Baron);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JokerSlot {
+    pub joker: JokerKind,
+    pub edition: Option<Edition>,
+}
+
+impl JokerSlot {
+    pub fn new(joker: impl Into<JokerKind>, edition: Option<Edition>) -> Self {
+        Self {
+            joker: joker.into(),
+            edition,
+        }
+    }
+}
+
+impl From<Joker> for JokerKind {
+    fn from(joker: Joker) -> Self {
+        JokerKind::Standard(joker)
+    }
+}
+
+impl PartialEq<Joker> for JokerKind {
+    fn eq(&self, other: &Joker) -> bool {
+        *self == JokerKind::Standard(*other)
+    }
+}
+
+impl PartialEq<&str> for JokerKind {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, JokerKind::Custom(name) if name == other)
+    }
+}
+
+impl From<JokerCard> for JokerSlot {
+    fn from(card: JokerCard) -> Self {
+        JokerSlot::new(card.joker, card.edition)
+    }
+}
+
+impl Display for JokerKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JokerKind::Standard(joker) => write!(f, "{joker}"),
+            JokerKind::Custom(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl Display for JokerSlot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.joker)?;
+        if let Some(edition) = self.edition {
+            write!(f, " {edition}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JokerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("empty Joker name".to_string());
+        }
+        Ok(s.parse::<Joker>()
+            .map_or_else(|_| JokerKind::custom(s), JokerKind::Standard))
+    }
+}
+
+impl FromStr for JokerSlot {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut joker_str = s;
+        let mut edition = None;
+
+        for possible_edition in [Edition::Foil, Edition::Holographic, Edition::Polychrome] {
+            if let Some(leftover) = s.strip_suffix(&possible_edition.to_string()) {
+                joker_str = leftover.trim();
+                edition = Some(possible_edition);
+                break;
+            }
+        }
+
+        let joker: JokerKind = joker_str
+            .parse()
+            .map_err(|err| format!("Invalid JokerCard `{s}`: {err}"))?;
+
+        Ok(JokerSlot::new(joker, edition))
+    }
+}
+
+impl<'de> Deserialize<'de> for JokerSlot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SlotVisitor;
+        impl Visitor<'_> for SlotVisitor {
+            type Value = JokerSlot;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "JokerCard")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(SlotVisitor)
+    }
+}
@@ -1,545 +1,636 @@
 //! # Jokers
 //!
 //! This module defines how special Joker cards modify chip and multiplier values.
-//! Jokers are divided into categories (easy, medium, hard, etc.), each applying
-//! different rules depending on the current hand, held cards, or scored cards.
 //!
-//! These functions are called by the scoring pipeline to apply Joker effects
-//! after the base hand value and enhancements/editions are computed.
+//! Every Joker ability is a [`JokerEffect`]: it declares *when* it fires
+//! ([`Activation`]) and *what* it does to the running `(Chips, Mult)`. A
+//! [`JokerRegistry`] maps each [`JokerKind`] to its effect, and the scoring
+//! pipeline dispatches through it: on‑scored and on‑held Jokers fire card by
+//! card during the card phases, and independent Jokers fire left to right in
+//! the Joker phase.
+//!
+//! The built‑in effects live in [`joker_effects`](crate::poker::joker_effects).
+//! House‑rule Jokers can be added to a registry without touching this module,
+//! and named in round files like any other Joker:
+//!
+//! ```
+//! use ortalib::{Chips, Mult};
+//! use ortalab::poker::explain::Explain;
+//! use ortalab::poker::joker_slot::JokerKind;
+//! use ortalab::poker::jokers::{Activation, JokerContext, JokerEffect, JokerRegistry};
+//! use ortalab::poker::round::ScoringRound;
+//! use ortalab::poker::scoring::score_with_registry;
+//!
+//! /// +1 Mult for every card held in hand.
+//! struct PocketJoker;
+//!
+//! impl JokerEffect for PocketJoker {
+//!     fn activation(&self) -> Activation {
+//!         Activation::Independent
+//!     }
+//!
+//!     fn independent(&self, ctx: &JokerContext, (chip, mul): (Chips, Mult)) -> (Chips, Mult) {
+//!         (chip, mul + ctx.held_cards.len() as f64)
+//!     }
+//! }
+//!
+//! let mut registry = JokerRegistry::standard();
+//! registry.register(JokerKind::custom("Pocket Joker"), PocketJoker);
+//!
+//! let round: ScoringRound = serde_yaml::from_str(
+//!     "cards_played: [A♥]\ncards_held_in_hand: [2♣, 3♣]\njokers: [Pocket Joker Foil]",
+//! )
+//! .unwrap();
+//!
+//! let breakdown =
+//!     score_with_registry(round.clone(), &registry, &mut Explain::disabled()).unwrap();
+//! assert_eq!(breakdown.after_jokers, (66.0, 3.0)); // High Card A, +50 Chips, +2 Mult
+//!
+//! // Without the registration, the Joker does not exist
+//! let standard = JokerRegistry::builtin();
+//! assert!(score_with_registry(round, standard, &mut Explain::disabled()).is_err());
+//! ```
 
 use crate::poker::{
-    apply_edition, compute_card_order, determine_current_suit, determine_total_colors,
+    apply_edition,
+    cards::PlayingCard,
+    context::RoundContext,
+    explain::Explain,
+    joker_effects::{OOPS_ALL_SIXES, register_standard_jokers},
+    joker_slot::{JokerKind, JokerSlot},
+    luck::Luck,
+    number::Number,
+    resolution::resolve_jokers,
 };
-use ordered_float::OrderedFloat;
-use ortalib::{Card, Chips, Enhancement, Joker, JokerCard, Mult, PokerHand, Rank, Suit, SuitColor};
-
-/// Applies the effects of "easy" Jokers to the current score.
-///
-/// Easy Jokers include:
-/// - **Base Joker**: +4 multiplier
-/// - **Jolly Joker**: +8 multiplier if the hand is a pair, trips, full house, etc.
-/// - **Sly Joker**: +50 chips for the same hand types
-/// - **Zany Joker**: +12 multiplier for trips/full house/quads/etc.
-/// - **Wily Joker**: +100 chips for the same
-/// - **Mad Joker**: +10 multiplier for two pair/full house
-/// - **Clever Joker**: +80 chips for the same
-/// - **Crazy Joker**: +12 multiplier for straights
-/// - **Devious Joker**: +100 chips for straights
-/// - **Droll Joker**: +10 multiplier for flushes/quads
-/// - **Crafty Joker**: +80 chips for the same
-/// - **Abstract Joker**: +3 multiplier per Joker card in play
-///
-/// # Arguments
-/// * `joker` — The Joker being applied.
-/// * `hand` — The detected [`PokerHand`].
-/// * `joker_cards_len` — Total number of jokers in play.
-/// * `chip` — Current chip value.
-/// * `mul` — Current multiplier value.
-///
-/// # Returns
-/// A tuple `(Chips, Mult)` with updated values.
+use ortalib::{Edition, Enhancement, Joker, PokerHand, Rank, Suit};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, OnceLock},
+};
+
+/// When a Joker's ability fires during scoring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Activation {
+    /// Once, in the Joker phase, after every card has been scored.
+    Independent,
+
+    /// Once for every trigger of every scored card.
+    OnScored,
+
+    /// Once for every trigger of every card held in hand.
+    OnHeld,
+
+    /// Never: the pipeline looks for the Joker directly (e.g. Brainstorm).
+    Special,
+}
+
+/// Everything a Joker may look at while it fires.
 ///
 /// # Example
 /// ```
-/// use ortalib::{PokerHand, Joker};
-/// use ortalab::poker::jokers::apply_easy_jokers;
+/// use ortalib::{Joker, PokerHand};
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::jokers::JokerContext;
 ///
-/// // Base Joker always adds +4 multiplier
-/// let (chips, mult) = apply_easy_jokers(Joker::Joker, PokerHand::Pair, 1, 100.0, 1.0);
-/// assert_eq!(chips, 100.0);
-/// assert_eq!(mult, 5.0);
+/// let jokers = vec![JokerSlot::new(Joker::Pareidolia, None)];
+/// let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &[], &[]);
+/// assert!(ctx.is_pareidolia_exists);
+/// assert!(!ctx.is_smeared_exists);
 /// ```
-pub fn apply_easy_jokers(
-    joker: Joker,
-    hand: PokerHand,
-    joker_cards_len: usize,
-    chip: f64,
-    mul: f64,
-) -> (Chips, Mult) {
-    let mut res = (chip, mul);
-
-    // Base Joker
-    if joker == Joker::Joker {
-        res.1 += 4.0;
-        // dbg!(res);
-    }
+#[derive(Clone, Copy, Debug)]
+pub struct JokerContext<'a> {
+    /// The detected poker hand.
+    pub hand: PokerHand,
+    /// Every Joker in play, left to right.
+    pub jokers: &'a [JokerSlot],
+    /// The scored cards, in played order.
+    pub scored_cards: &'a [PlayingCard],
+    /// The cards held in hand.
+    pub held_cards: &'a [PlayingCard],
+    /// Whether **Pareidolia** is in play (every card is a face card).
+    pub is_pareidolia_exists: bool,
+    /// Whether **Smeared Joker** is in play (suits of the same colour match).
+    pub is_smeared_exists: bool,
+    /// The game state the round is played in (see [`with_round`](Self::with_round)).
+    pub round: &'a RoundContext,
+    /// Where random outcomes come from; without one, every roll fails (see
+    /// [`roll`](Self::roll)).
+    pub luck: Option<&'a Luck>,
+}
 
-    // Jolly and Sly Joker
-    let mut check = vec![
-        PokerHand::Pair,
-        PokerHand::TwoPair,
-        PokerHand::FullHouse,
-        PokerHand::ThreeOfAKind,
-        PokerHand::FourOfAKind,
-        PokerHand::FiveOfAKind,
-        PokerHand::FlushHouse,
-        PokerHand::FlushFive,
-    ];
-    if check.contains(&hand) {
-        if joker == Joker::JollyJoker {
-            res.1 += 8.0;
-            // dbg!(res);
-        } else if joker == Joker::SlyJoker {
-            res.0 += 50.0;
+/// The context of a round that does not give one.
+static DEFAULT_ROUND: LazyLock<RoundContext> = LazyLock::new(RoundContext::default);
+
+impl<'a> JokerContext<'a> {
+    pub fn new(
+        hand: PokerHand,
+        jokers: &'a [JokerSlot],
+        scored_cards: &'a [PlayingCard],
+        held_cards: &'a [PlayingCard],
+    ) -> Self {
+        Self {
+            hand,
+            jokers,
+            scored_cards,
+            held_cards,
+            is_pareidolia_exists: jokers.iter().any(|slot| slot.joker == Joker::Pareidolia),
+            is_smeared_exists: jokers.iter().any(|slot| slot.joker == Joker::SmearedJoker),
+            round: &DEFAULT_ROUND,
+            luck: None,
         }
     }
 
-    // Zany and Willy Joker
-    check = vec![
-        PokerHand::ThreeOfAKind,
-        PokerHand::FullHouse,
-        PokerHand::FlushHouse,
-        PokerHand::FourOfAKind,
-        PokerHand::FiveOfAKind,
-        PokerHand::FlushFive,
-    ];
-    if check.contains(&hand) {
-        if joker == Joker::ZanyJoker {
-            res.1 += 12.0;
-            // dbg!(res);
-        } else if joker == Joker::WilyJoker {
-            res.0 += 100.0;
-        }
+    /// The same context, with the game state in `round`.
+    pub fn with_round(self, round: &'a RoundContext) -> Self {
+        Self { round, ..self }
     }
 
-    // Mad and Clever Joker
-    check = vec![
-        PokerHand::TwoPair,
-        PokerHand::FullHouse,
-        PokerHand::FlushHouse,
-    ];
-    if check.contains(&hand) {
-        if joker == Joker::MadJoker {
-            res.1 += 10.0;
-        } else if joker == Joker::CleverJoker {
-            res.0 += 80.0;
+    /// The same context, rolling every chance with `luck`.
+    pub fn with_luck(self, luck: &'a Luck) -> Self {
+        Self {
+            luck: Some(luck),
+            ..self
         }
     }
 
-    // Crazy and Devious Joker
-    check = vec![PokerHand::Straight, PokerHand::StraightFlush];
-    if check.contains(&hand) {
-        if joker == Joker::CrazyJoker {
-            res.1 += 12.0;
-        } else if joker == Joker::DeviousJoker {
-            res.0 += 100.0;
-        }
+    /// Whether an event with `numerator` in `denominator` odds happens.
+    ///
+    /// Each **Oops! All 6s** in play doubles the odds.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalib::PokerHand;
+    /// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+    /// use ortalab::poker::jokers::JokerContext;
+    /// use ortalab::poker::luck::Luck;
+    ///
+    /// let luck = Luck::seeded(0);
+    /// let ctx = JokerContext::new(PokerHand::HighCard, &[], &[], &[]);
+    /// assert!(!ctx.roll(1, 2)); // no luck, no success
+    ///
+    /// let jokers = [JokerSlot::new(JokerKind::custom("Oops! All 6s"), None)];
+    /// let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &[], &[]).with_luck(&luck);
+    /// assert!(ctx.roll(1, 2)); // 2 in 2
+    /// ```
+    pub fn roll(&self, numerator: u32, denominator: u32) -> bool {
+        let numerator = self.odds(numerator);
+        self.luck
+            .is_some_and(|luck| luck.roll(numerator, denominator))
     }
 
-    // Droll and Crafty Joker
-    check = vec![
-        PokerHand::Flush,
-        PokerHand::FlushFive,
-        PokerHand::FlushHouse,
-        PokerHand::FourOfAKind,
-    ];
-    if check.contains(&hand) {
-        if joker == Joker::DrollJoker {
-            res.1 += 10.0;
-        } else if joker == Joker::CraftyJoker {
-            res.0 += 80.0;
-        }
+    /// Like [`roll`](Self::roll), for an event that never changes the score
+    /// (see [`Luck::roll_unscored`]).
+    pub fn roll_unscored(&self, numerator: u32, denominator: u32) -> bool {
+        let numerator = self.odds(numerator);
+        self.luck
+            .is_some_and(|luck| luck.roll_unscored(numerator, denominator))
     }
 
-    // Abstract Joker
-    if joker == Joker::AbstractJoker {
-        res.1 += 3.0 * joker_cards_len as f64;
-        // dbg!(res);
+    /// `numerator` doubled for each Oops! All 6s in play.
+    fn odds(&self, numerator: u32) -> u32 {
+        let oops = self
+            .jokers
+            .iter()
+            .filter(|slot| slot.joker == OOPS_ALL_SIXES)
+            .count() as u32;
+        numerator.saturating_mul(2u32.saturating_pow(oops))
     }
 
-    (res.0, res.1)
+    /// Whether `card` counts as a face card (always, with Pareidolia, unless
+    /// it is a Stone card).
+    pub fn is_face(&self, card: &PlayingCard) -> bool {
+        self.rank(card).is_some() && (card.rank.is_face() || self.is_pareidolia_exists)
+    }
+
+    /// The rank of `card`, or `None` for a Stone card.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalib::{PokerHand, Rank};
+    /// use ortalab::poker::cards::parse_card;
+    /// use ortalab::poker::jokers::JokerContext;
+    ///
+    /// let ctx = JokerContext::new(PokerHand::HighCard, &[], &[], &[]);
+    /// assert_eq!(ctx.rank(&parse_card("K♠").unwrap()), Some(Rank::King));
+    /// assert_eq!(ctx.rank(&parse_card("K♠ Stone").unwrap()), None);
+    /// ```
+    pub fn rank(&self, card: &PlayingCard) -> Option<Rank> {
+        (!card.is_stone()).then_some(card.rank)
+    }
+
+    /// Whether `card` counts as `suit`: Wild cards always do, and with
+    /// Smeared Joker so does the other suit of the same colour. Stone cards
+    /// never do.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalib::{Card, Joker, PokerHand, Rank, Suit};
+    /// use ortalab::poker::joker_slot::JokerSlot;
+    /// use ortalab::poker::jokers::JokerContext;
+    /// use ortalab::poker::cards::PlayingCard;
+    ///
+    /// let heart = PlayingCard::from(Card::new(Rank::Two, Suit::Hearts, None, None));
+    /// let ctx = JokerContext::new(PokerHand::HighCard, &[], &[], &[]);
+    /// assert!(!ctx.is_suit(&heart, Suit::Diamonds));
+    ///
+    /// let jokers = vec![JokerSlot::new(Joker::SmearedJoker, None)];
+    /// let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &[], &[]);
+    /// assert!(ctx.is_suit(&heart, Suit::Diamonds));
+    /// ```
+    pub fn is_suit(&self, card: &PlayingCard, suit: Suit) -> bool {
+        let same_color = |other: Suit| other.color() == suit.color();
+        if card.is_stone() {
+            return false;
+        }
+        card.suit == suit
+            || (self.is_smeared_exists && same_color(card.suit))
+            || card.enhancement == Some(Enhancement::Wild)
+    }
 }
 
-/// Applies the effects of "medium" Jokers to the current score.
-///
-/// Medium Jokers include:
-/// - **Raised Fist**: Adds multiplier based on lowest rank in hand
-/// - **Blackboard Joker**: Triples multiplier if all held cards are black suits
-/// - **Baron Joker**: Multiplier ×1.5 for each King held
-/// - **Greedy/Lusty/Wrathful/Gluttonous Jokers**: +3 multiplier per card of a specific suit
-/// - **Fibonacci Joker**: +8 multiplier for cards with Fibonacci ranks (A,2,3,5,8)
-/// - **Scary Face**: +30 chips per face card
-/// - **Even Steven**: +4 multiplier per even‑ranked card ≤ 10
-/// - **Odd Todd**: +31 chips per odd‑ranked card (or Ace)
-/// - **Photograph**: Doubles multiplier if at least one face card is present
-/// - **Smiley Face**: +5 multiplier per face card
-/// - **Flower Pot**: Triples multiplier if all suits (or both colors with Smear) are present
+/// The ability of one Joker.
 ///
-/// # Arguments
-/// * `joker` — The Joker being applied.
-/// * `on_held` — Cards currently held in hand.
-/// * `on_scored` — Cards being scored.
-/// * `chip` — Current chip value.
-/// * `mul` — Current multiplier value.
-/// * `is_pareidolia_exists` — Whether Pareidolia Joker is active (treats all cards as faces).
-/// * `is_smeared_exists` — Whether Smear Joker is active (treats red/black suits as equivalent).
+/// Only the method matching [`activation`](JokerEffect::activation) is ever
+/// called; the others default to leaving the score unchanged. An effect
+/// implemented for every [`Number`] also works with exact arithmetic (see
+/// [`score_exact`](crate::poker::scoring::score_exact)).
+pub trait JokerEffect<N: Number = f64>: Send + Sync {
+    /// The phase in which this ability fires.
+    fn activation(&self) -> Activation;
+
+    /// Fires on one trigger of a scored `card`.
+    fn on_scored(&self, _ctx: &JokerContext, _card: &PlayingCard, score: (N, N)) -> (N, N) {
+        score
+    }
+
+    /// Fires on one trigger of a `card` held in hand.
+    fn on_held(&self, _ctx: &JokerContext, _card: &PlayingCard, score: (N, N)) -> (N, N) {
+        score
+    }
+
+    /// Fires once in the Joker phase.
+    fn independent(&self, _ctx: &JokerContext, score: (N, N)) -> (N, N) {
+        score
+    }
+}
+
+/// Maps every Joker to its [`JokerEffect`].
 ///
-/// # Returns
-/// A tuple `(Chips, Mult)` with updated values.
+/// `ortalib` Jokers without an entry (e.g. Splash, Mime) have no ability of
+/// their own and are skipped by the pipeline. A [`JokerKind::Custom`] Joker
+/// without an entry is unknown, and its round cannot be scored.
 ///
 /// # Example
 /// ```
-/// use ortalib::{Card, Rank, Suit, Joker};
-/// use ortalab::poker::jokers::apply_medium_jokers;
+/// use ortalib::Joker;
+/// use ortalab::poker::jokers::{Activation, JokerRegistry};
 ///
-/// // Even Steven: +4 multiplier for even ranks ≤ 10
-/// let cards = vec![
-///     Card::new(Rank::Two, Suit::Clubs, None, None),
-///     Card::new(Rank::Four, Suit::Hearts, None, None),
-/// ];
+/// let registry = JokerRegistry::builtin();
+/// let effect = registry.get(&Joker::Photograph.into()).unwrap();
+/// assert_eq!(effect.activation(), Activation::OnScored);
 ///
-/// let (chips, mult) = apply_medium_jokers(
-///     Joker::EvenSteven,
-///     &[],
-///     &cards,
-///     100.0,
-///     1.0,
-///     false,
-///     false,
-/// );
+/// assert!(JokerRegistry::<f64>::new().get(&Joker::Photograph.into()).is_none());
 ///
-/// assert_eq!(chips, 100.0);
-/// assert_eq!(mult, 9.0); // +4 for each even card
+/// let bloodstone = "Bloodstone".parse().unwrap();
+/// assert!(registry.is_known(&bloodstone));
+/// assert!(!registry.is_known(&"Bloodstne".parse().unwrap()));
 /// ```
-pub fn apply_medium_jokers(
-    joker: Joker,
-    on_held: &[Card],
-    on_scored: &[Card],
-    chip: f64,
-    mul: f64,
-    is_pareidolia_exists: bool,
-    is_smeared_exists: bool,
-) -> (Chips, Mult) {
-    let mut res = (chip, mul);
-    let mut on_held_iter = on_held.iter();
-
-    if joker == Joker::RaisedFist {
-        let lowest_rank_card = on_held
-            .iter()
-            .min_by_key(|&card| OrderedFloat(card.rank.rank_value()))
-            .unwrap();
-        let mut vec_lowest_rank_card = on_held
-            .iter()
-            .filter(|&card| card.rank.rank_value() == lowest_rank_card.rank.rank_value());
-        res.1 += vec_lowest_rank_card.next_back().unwrap().rank.rank_value() * 2.0;
-
-        // TODO retriggers
-    }
-
-    // Blackboard Joker
-    if joker == Joker::Blackboard {
-        let vec_to_check = [Suit::Spades, Suit::Clubs];
-        let check = on_held_iter.all(|c| {
-            if let Some(enhance) = c.enhancement
-                && enhance == Enhancement::Wild
-            {
-                return true;
-            }
-            vec_to_check.contains(&c.suit)
-        });
+pub struct JokerRegistry<N: Number = f64> {
+    effects: HashMap<JokerKind, Box<dyn JokerEffect<N>>>,
+}
 
-        if check || on_held.is_empty() {
-            res.1 *= 3.0;
+impl<N: Number> Default for JokerRegistry<N> {
+    fn default() -> Self {
+        Self {
+            effects: HashMap::new(),
         }
     }
+}
 
-    // Baron Joker
-    if joker == Joker::Baron {
-        on_held_iter.for_each(|&card| {
-            if card.rank == Rank::King {
-                res.1 *= 1.5;
-            }
-        });
-        // dbg!(res);
-    }
-
-    // Greedy Joker
-    if joker == Joker::GreedyJoker {
-        on_scored.iter().for_each(|&card| {
-            if card.suit == Suit::Diamonds || (card.suit == Suit::Hearts && is_smeared_exists) {
-                res.1 += 3.0;
-            } else if let Some(enhance) = card.enhancement
-                && enhance == Enhancement::Wild
-            {
-                res.1 += 3.0;
-            }
-        })
+impl JokerRegistry {
+    /// The shared registry of built‑in Jokers used by [`score`](crate::poker::score).
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<JokerRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(Self::standard)
     }
+}
 
-    // Lusty Joker
-    if joker == Joker::LustyJoker {
-        on_scored.iter().for_each(|&card| {
-            if card.suit == Suit::Hearts || (card.suit == Suit::Diamonds && is_smeared_exists) {
-                res.1 += 3.0;
-            } else if let Some(enhance) = card.enhancement
-                && enhance == Enhancement::Wild
-            {
-                res.1 += 3.0;
-            }
-        })
+impl<N: Number> JokerRegistry<N> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Wrathful Joker
-    if joker == Joker::WrathfulJoker {
-        on_scored.iter().for_each(|&card| {
-            if card.suit == Suit::Spades || (card.suit == Suit::Clubs && is_smeared_exists) {
-                res.1 += 3.0;
-            } else if let Some(enhance) = card.enhancement
-                && enhance == Enhancement::Wild
-            {
-                res.1 += 3.0;
-            }
-        })
+    /// Creates a registry holding every built‑in Joker, ready for extra
+    /// registrations.
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        register_standard_jokers(&mut registry);
+        registry
     }
 
-    // Gluttonus Joker
-    if joker == Joker::GluttonousJoker {
-        on_scored.iter().for_each(|&card| {
-            if card.suit == Suit::Clubs || (card.suit == Suit::Spades && is_smeared_exists) {
-                res.1 += 3.0;
-            } else if let Some(enhance) = card.enhancement
-                && enhance == Enhancement::Wild
-            {
-                res.1 += 3.0;
-            }
-        })
+    /// Registers `effect` as the ability of `joker`, replacing any previous one.
+    pub fn register(
+        &mut self,
+        joker: impl Into<JokerKind>,
+        effect: impl JokerEffect<N> + 'static,
+    ) -> &mut Self {
+        self.effects.insert(joker.into(), Box::new(effect));
+        self
     }
 
-    // Fibonacci Joker
-    if joker == Joker::Fibonacci {
-        on_scored.iter().for_each(|&card| {
-            if card.rank == Rank::Ace
-                || card.rank == Rank::Two
-                || card.rank == Rank::Three
-                || card.rank == Rank::Five
-                || card.rank == Rank::Eight
-            {
-                res.1 += 8.0;
-            }
-        })
+    /// Looks up the ability of `joker`.
+    pub fn get(&self, joker: &JokerKind) -> Option<&dyn JokerEffect<N>> {
+        self.effects.get(joker).map(Box::as_ref)
     }
 
-    // Scary Face
-    if joker == Joker::ScaryFace {
-        on_scored.iter().for_each(|&card| {
-            if card.rank.is_face() || is_pareidolia_exists {
-                res.0 += 30.0;
-            }
-        })
+    /// Whether `joker` exists: every `ortalib` Joker does, and a
+    /// [`JokerKind::Custom`] one once registered.
+    pub fn is_known(&self, joker: &JokerKind) -> bool {
+        matches!(joker, JokerKind::Standard(_)) || self.effects.contains_key(joker)
     }
 
-    // Even Steven
-    if joker == Joker::EvenSteven {
-        on_scored.iter().for_each(|&card| {
-            let value = compute_card_order(card);
-            if value <= 10.0 && value % 2.0 == 0.0 {
-                res.1 += 4.0;
-            }
-        })
+    /// Pairs every Joker slot with the effect of the ability it resolves to
+    /// (see [`resolve_jokers`]), keeping only the effects that fire in `phase`.
+    fn active<'a>(
+        &'a self,
+        jokers: &'a [JokerSlot],
+        phase: Activation,
+    ) -> impl Iterator<Item = (&'a JokerSlot, JokerKind, &'a dyn JokerEffect<N>)> {
+        jokers
+            .iter()
+            .zip(resolve_jokers(jokers))
+            .filter_map(move |(slot, ability)| {
+                let joker = ability?;
+                let effect = self.get(&joker)?;
+                (effect.activation() == phase).then_some((slot, joker, effect))
+            })
     }
+}
 
-    // Odd Todd
-    if joker == Joker::OddTodd {
-        on_scored.iter().for_each(|&card| {
-            let value = compute_card_order(card);
-            if value == 14.0 || (value < 10.0 && value % 2.0 != 0.0) {
-                res.0 += 31.0;
-            }
-        })
-    }
+/// Applies every on‑scored Joker, left to right, to one trigger of a scored card.
+///
+/// This runs right after the card's own chips, enhancement and edition, so
+/// each card resolves fully before the next one.
+///
+/// # Arguments
+/// * `registry` — The Joker effects to dispatch through.
+/// * `ctx` — The round being scored.
+/// * `card` — The card being scored.
+/// * `chip` — Current chip value.
+/// * `mul` — Current multiplier value.
+/// * `explain` — Trace that receives one step per Joker that fired.
+///
+/// # Returns
+/// A tuple `(Chips, Mult)` with updated values.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, PokerHand, Rank, Suit};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::jokers::{JokerContext, JokerRegistry, apply_scored_card_jokers};
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let king = PlayingCard::from(Card::new(Rank::King, Suit::Diamonds, None, None));
+/// let jokers = vec![
+///     JokerSlot::new(Joker::GreedyJoker, None),
+///     JokerSlot::new(Joker::Photograph, None),
+/// ];
+/// let scored = [king];
+/// let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &scored, &[]);
+///
+/// let (chips, mult) = apply_scored_card_jokers(
+///     JokerRegistry::builtin(),
+///     &ctx,
+///     &king,
+///     10.0,
+///     1.0,
+///     &mut Explain::disabled(),
+/// );
+/// assert_eq!(chips, 10.0);
+/// assert_eq!(mult, 8.0); // (1 + 3) x 2
+/// ```
+pub fn apply_scored_card_jokers<N: Number>(
+    registry: &JokerRegistry<N>,
+    ctx: &JokerContext,
+    card: &PlayingCard,
+    chip: N,
+    mul: N,
+    explain: &mut Explain,
+) -> (N, N) {
+    let mut new_result = (chip, mul);
 
-    // Photograph
-    if joker == Joker::Photograph {
-        let mut firt_check = false;
-        on_scored.iter().for_each(|&card| {
-            if (card.rank.is_face() || is_pareidolia_exists) && !firt_check {
-                res.1 *= 2.0;
-                firt_check = true;
-            }
+    registry
+        .active(ctx.jokers, Activation::OnScored)
+        .for_each(|(slot, joker, effect)| {
+            let result = effect.on_scored(ctx, card, new_result.clone());
+            record_joker(explain, &slot.joker, &joker, &new_result, &result);
+            new_result = result;
+        });
 
-            // TODO: Handle retriggers
-        })
-    }
+    new_result
+}
 
-    // Smiley Face
-    if joker == Joker::SmileyFace {
-        on_scored.iter().for_each(|&card| {
-            if card.rank.is_face() || is_pareidolia_exists {
-                res.1 += 5.0;
-            }
-        })
-    }
+/// Applies every on‑held Joker, left to right, to one trigger of a held card.
+///
+/// This runs right after the card's own Steel effect.
+///
+/// # Arguments
+/// * `registry` — The Joker effects to dispatch through.
+/// * `ctx` — The round being scored.
+/// * `card` — The held card being triggered.
+/// * `chip` — Current chip value.
+/// * `mul` — Current multiplier value.
+/// * `explain` — Trace that receives one step per Joker that fired.
+///
+/// # Returns
+/// A tuple `(Chips, Mult)` with updated values.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, PokerHand, Rank, Suit};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::jokers::{JokerContext, JokerRegistry, apply_held_card_jokers};
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let king = PlayingCard::from(Card::new(Rank::King, Suit::Spades, None, None));
+/// let two = PlayingCard::from(Card::new(Rank::Two, Suit::Spades, None, None));
+/// let held = [king, two];
+/// let jokers = vec![
+///     JokerSlot::new(Joker::Baron, None),
+///     JokerSlot::new(Joker::RaisedFist, None),
+/// ];
+/// let ctx = JokerContext::new(PokerHand::HighCard, &jokers, &[], &held);
+/// let registry = JokerRegistry::builtin();
+///
+/// let mut explain = Explain::disabled();
+/// let (_, mult) = apply_held_card_jokers(registry, &ctx, &king, 10.0, 2.0, &mut explain);
+/// assert_eq!(mult, 3.0); // Baron only
+/// let (_, mult) = apply_held_card_jokers(registry, &ctx, &two, 10.0, 2.0, &mut explain);
+/// assert_eq!(mult, 6.0); // Raised Fist only
+/// ```
+pub fn apply_held_card_jokers<N: Number>(
+    registry: &JokerRegistry<N>,
+    ctx: &JokerContext,
+    card: &PlayingCard,
+    chip: N,
+    mul: N,
+    explain: &mut Explain,
+) -> (N, N) {
+    let mut new_result = (chip, mul);
 
-    // Flower pot
-    if joker == Joker::FlowerPot && on_scored.len() >= 4 {
-        let having_diamonds = determine_current_suit(on_scored, Suit::Diamonds);
-        let having_hearts = determine_current_suit(on_scored, Suit::Hearts);
-        let having_spades = determine_current_suit(on_scored, Suit::Spades);
-        let having_clubs = determine_current_suit(on_scored, Suit::Clubs);
-        let having_red_cards = determine_total_colors(on_scored, SuitColor::Red);
-        let having_black_cards = determine_total_colors(on_scored, SuitColor::Black);
-
-        if (having_diamonds && having_hearts && having_spades && having_clubs)
-            || (is_smeared_exists && having_red_cards && having_black_cards)
-        {
-            res.1 *= 3.0
-        }
-    }
+    registry
+        .active(ctx.jokers, Activation::OnHeld)
+        .for_each(|(slot, joker, effect)| {
+            let result = effect.on_held(ctx, card, new_result.clone());
+            record_joker(explain, &slot.joker, &joker, &new_result, &result);
+            new_result = result;
+        });
 
-    (res.0, res.1)
+    new_result
 }
 
-/// Applies all Joker effects to the current score.
+/// Applies the Joker phase to the current score.
 ///
-/// This function iterates over all Joker cards in play and applies their
-/// effects depending on their category:
+/// This function iterates over all Joker cards in play **from left to right**
+/// and applies, for each one:
 ///
-/// - **Independent Jokers** (e.g. Base Joker, Jolly Joker, Abstract Joker, Blackboard, Flower Pot)
-///   are applied regardless of held/scored context.
-/// - **On‑scored Jokers** (e.g. Greedy, Lusty, Wrathful, Gluttonous, Fibonacci, Scary Face, etc.)
-///   are applied based on the cards currently being scored.
-/// - **On‑held Jokers** (e.g. Raised Fist, Baron, Mime) are applied based on cards still in hand.
-/// - **Editions** attached to Joker cards are also applied at the end.
+/// - its **edition**, if Foil or Holographic;
+/// - its ability, if it is an **Independent Joker** (e.g. Base Joker, Jolly Joker,
+///   Abstract Joker, Blackboard, Flower Pot);
+/// - its **edition**, if Polychrome.
 ///
-/// The function also checks for global Joker effects like **Pareidolia** (treat all cards as faces)
-/// and **Smeared Joker** (treat red/black suits as equivalent).
+/// Because ×Mult and +Mult do not commute, the position of each Joker decides
+/// the result. On‑scored and on‑held Jokers have already fired during the card
+/// phases; see [`apply_scored_card_jokers`] and [`apply_held_card_jokers`].
+///
+/// **Blueprint** and **Brainstorm** act with the ability they copy, resolved per
+/// slot by [`resolve_jokers`]. Their own edition still applies.
 ///
 /// # Arguments
-/// * `joker_cards` — The Joker cards in play.
-/// * `on_held_cards` — Cards currently held in hand.
-/// * `on_scored_cards` — Cards being scored.
-/// * `hand` — The detected [`PokerHand`] for this round.
+/// * `registry` — The Joker effects to dispatch through.
+/// * `ctx` — The round being scored.
 /// * `chip` — Current chip value before Jokers.
 /// * `mul` — Current multiplier value before Jokers.
+/// * `explain` — Trace that receives one step per Joker (or Joker edition) that fired.
 ///
 /// # Returns
 /// A tuple `(Chips, Mult)` with updated values after all Joker effects.
 ///
 /// # Example
 /// ```
-/// use ortalib::{Card, Rank, Suit, Joker, JokerCard, PokerHand};
-/// use ortalab::poker::jokers::joker_application;
+/// use ortalib::{Card, Edition, Rank, Suit, Joker, PokerHand};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::jokers::{JokerContext, JokerRegistry, joker_application};
+/// use ortalab::poker::cards::PlayingCard;
 ///
 /// // Start with a simple hand: Pair of Kings
 /// let scored = vec![
-///     Card::new(Rank::King, Suit::Hearts, None, None),
-///     Card::new(Rank::King, Suit::Spades, None, None),
+///     PlayingCard::from(Card::new(Rank::King, Suit::Hearts, None, None)),
+///     PlayingCard::from(Card::new(Rank::King, Suit::Spades, None, None)),
 /// ];
+/// let registry = JokerRegistry::builtin();
 ///
 /// // Add a Base Joker (always +4 multiplier)
-/// let jokers = vec![JokerCard::new(Joker::Joker, None)];
+/// let jokers = vec![JokerSlot::new(Joker::Joker, None)];
+/// let ctx = JokerContext::new(PokerHand::Pair, &jokers, &scored, &[]);
 ///
-/// let (chips, mult) = joker_application(&jokers, &[], &scored, PokerHand::Pair, 100.0, 1.0);
+/// let mut explain = Explain::new(true);
+/// let (chips, mult) = joker_application(registry, &ctx, 100.0, 1.0, &mut explain);
 ///
 /// assert_eq!(chips, 100.0);
 /// assert_eq!(mult, 5.0); // base 1.0 + 4.0 from Joker
+/// assert_eq!(explain.steps()[0].label, "Joker");
+///
+/// // Order matters: a Polychrome Joker followed by a +Mult Joker
+/// let apply = |jokers: &[JokerSlot]| {
+///     let ctx = JokerContext::new(PokerHand::Pair, jokers, &scored, &[]);
+///     joker_application(registry, &ctx, 100.0, 1.0, &mut Explain::disabled())
+/// };
+/// let polychrome_first = [
+///     JokerSlot::new(Joker::Joker, Some(Edition::Polychrome)),
+///     JokerSlot::new(Joker::Joker, None),
+/// ];
+/// let polychrome_last = [
+///     JokerSlot::new(Joker::Joker, None),
+///     JokerSlot::new(Joker::Joker, Some(Edition::Polychrome)),
+/// ];
+/// assert_eq!(apply(&polychrome_first).1, 11.5); // (1 + 4) x 1.5 + 4
+/// assert_eq!(apply(&polychrome_last).1, 13.5); // (1 + 4 + 4) x 1.5
+///
+/// // Holographic applies before its Joker's ability
+/// let holographic = [JokerSlot::new(Joker::Blackboard, Some(Edition::Holographic))];
+/// assert_eq!(apply(&holographic).1, 33.0); // (1 + 10) x 3
 /// ```
-pub fn joker_application(
-    joker_cards: &[JokerCard],
-    on_held_cards: &[Card],
-    on_scored_cards: &[Card],
-    hand: PokerHand,
-    chip: f64,
-    mul: f64,
-) -> (Chips, Mult) {
+pub fn joker_application<N: Number>(
+    registry: &JokerRegistry<N>,
+    ctx: &JokerContext,
+    chip: N,
+    mul: N,
+    explain: &mut Explain,
+) -> (N, N) {
     let mut new_result = (chip, mul);
-    let independent_jokers = [
-        Joker::Joker,
-        Joker::JollyJoker,
-        Joker::ZanyJoker,
-        Joker::MadJoker,
-        Joker::CrazyJoker,
-        Joker::DrollJoker,
-        Joker::SlyJoker,
-        Joker::WilyJoker,
-        Joker::CleverJoker,
-        Joker::DeviousJoker,
-        Joker::CraftyJoker,
-        Joker::AbstractJoker,
-        Joker::Blackboard,
-        Joker::FlowerPot,
-    ];
-    let on_scored_jokers = [
-        Joker::GreedyJoker,
-        Joker::LustyJoker,
-        Joker::WrathfulJoker,
-        Joker::GluttonousJoker,
-        Joker::Fibonacci,
-        Joker::ScaryFace,
-        Joker::EvenSteven,
-        Joker::OddTodd,
-        Joker::Photograph,
-        Joker::SmileyFace,
-        Joker::SockAndBuskin,
-    ];
-    let on_held_jokers = [Joker::RaisedFist, Joker::Baron, Joker::Mime];
-    let is_pareidolia_exists = joker_cards
-        .iter()
-        .any(|card| card.joker == Joker::Pareidolia);
-    let is_smeared_exists = joker_cards
-        .iter()
-        .any(|card| card.joker == Joker::SmearedJoker);
 
-    joker_cards
-        .iter()
-        .filter(|card| on_scored_jokers.contains(&card.joker))
-        .for_each(|card| {
-            new_result = apply_medium_jokers(
-                card.joker,
-                on_held_cards,
-                on_scored_cards,
-                new_result.0,
-                new_result.1,
-                is_pareidolia_exists,
-                is_smeared_exists,
-            );
-        });
+    // Each slot acts with the ability it resolves to (Blueprint, Brainstorm)
+    let resolved_jokers = resolve_jokers(ctx.jokers);
 
-    joker_cards
+    // Jokers resolve strictly left to right, each one together with its edition
+    ctx.jokers
         .iter()
-        .filter(|card| on_held_jokers.contains(&card.joker))
-        .for_each(|card| {
-            new_result = apply_medium_jokers(
-                card.joker,
-                on_held_cards,
-                on_scored_cards,
-                new_result.0,
-                new_result.1,
-                is_pareidolia_exists,
-                is_smeared_exists,
-            );
-        });
+        .zip(resolved_jokers)
+        .for_each(|(card, ability)| {
+            // Foil and Holographic apply before the Joker's ability
+            if let Some(edition) = card.edition
+                && edition != Edition::Polychrome
+            {
+                let (chip, mul) = new_result.clone();
+                new_result = apply_edition(edition, chip, mul, false);
+                explain.record(|| format!("{} {edition}", card.joker), &new_result);
+            }
 
-    joker_cards
-        .iter()
-        .filter(|card| independent_jokers.contains(&card.joker))
-        .for_each(|card| {
-            new_result = apply_easy_jokers(
-                card.joker,
-                hand,
-                joker_cards.len(),
-                new_result.0,
-                new_result.1,
-            );
-            new_result = apply_medium_jokers(
-                card.joker,
-                on_held_cards,
-                on_scored_cards,
-                new_result.0,
-                new_result.1,
-                is_pareidolia_exists,
-                is_smeared_exists,
-            );
+            if let Some(joker) = ability
+                && let Some(effect) = registry.get(&joker)
+                && effect.activation() == Activation::Independent
+            {
+                let result = effect.independent(ctx, new_result.clone());
+                record_joker(explain, &card.joker, &joker, &new_result, &result);
+                new_result = result;
+            }
+
+            // Polychrome applies after the Joker's ability
+            if card.edition == Some(Edition::Polychrome) {
+                let (chip, mul) = new_result.clone();
+                new_result = apply_edition(Edition::Polychrome, chip, mul, false);
+                explain.record(|| format!("{} Polychrome", card.joker), &new_result);
+            }
         });
 
-    // Apply edition
-    joker_cards.iter().for_each(|card| {
-        new_result = if let Some(edition) = card.edition {
-            apply_edition(edition, new_result.0, new_result.1, false)
-        } else {
-            new_result
-        };
-    });
+    new_result
+}
 
-    (new_result.0, new_result.1)
+/// Records a Joker step in `explain`, but only if the Joker changed the score.
+///
+/// A slot that copied another Joker's ability is shown as `Slot (Copied)`.
+fn record_joker<N: Number>(
+    explain: &mut Explain,
+    slot: &JokerKind,
+    joker: &JokerKind,
+    before: &(N, N),
+    after: &(N, N),
+) {
+    if before != after {
+        explain.record(
+            || {
+                if slot == joker {
+                    joker.to_string()
+                } else {
+                    format!("{slot} ({joker})")
+                }
+            },
+            after,
+        );
+    }
 }
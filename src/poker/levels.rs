@@ -0,0 +1,83 @@
+//! # Hand Levels
+//!
+//! Planet cards level up a poker hand. Each level above 1 adds the hand's
+//! per‑level Chips and Mult to its base value:
+//!
+//! | Hand            | Planet   | +Chips | +Mult |
+//! |-----------------|----------|--------|-------|
+//! | High Card       | Pluto    | 10     | 1     |
+//! | Pair            | Mercury  | 15     | 1     |
+//! | Two Pair        | Uranus   | 20     | 1     |
+//! | Three Of A Kind | Venus    | 20     | 2     |
+//! | Straight        | Saturn   | 30     | 3     |
+//! | Flush           | Jupiter  | 15     | 2     |
+//! | Full House      | Earth    | 25     | 2     |
+//! | Four Of A Kind  | Mars     | 30     | 3     |
+//! | Straight Flush  | Neptune  | 40     | 4     |
+//! | Five Of A Kind  | Planet X | 35     | 3     |
+//! | Flush House     | Ceres    | 40     | 4     |
+//! | Flush Five      | Eris     | 50     | 3     |
+
+use ortalib::{Chips, Mult, PokerHand};
+
+/// The Chips and Mult one level adds to `hand`.
+pub fn per_level(hand: PokerHand) -> (Chips, Mult) {
+    match hand {
+        PokerHand::HighCard => (10.0, 1.0),
+        PokerHand::Pair => (15.0, 1.0),
+        PokerHand::TwoPair => (20.0, 1.0),
+        PokerHand::ThreeOfAKind => (20.0, 2.0),
+        PokerHand::Straight => (30.0, 3.0),
+        PokerHand::Flush => (15.0, 2.0),
+        PokerHand::FullHouse => (25.0, 2.0),
+        PokerHand::FourOfAKind => (30.0, 3.0),
+        PokerHand::StraightFlush => (40.0, 4.0),
+        PokerHand::FiveOfAKind => (35.0, 3.0),
+        PokerHand::FlushHouse => (40.0, 4.0),
+        PokerHand::FlushFive => (50.0, 3.0),
+    }
+}
+
+/// The Planet card that levels up `hand`.
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::levels::planet;
+///
+/// assert_eq!(planet(PokerHand::Pair), "Mercury");
+/// ```
+pub fn planet(hand: PokerHand) -> &'static str {
+    match hand {
+        PokerHand::HighCard => "Pluto",
+        PokerHand::Pair => "Mercury",
+        PokerHand::TwoPair => "Uranus",
+        PokerHand::ThreeOfAKind => "Venus",
+        PokerHand::Straight => "Saturn",
+        PokerHand::Flush => "Jupiter",
+        PokerHand::FullHouse => "Earth",
+        PokerHand::FourOfAKind => "Mars",
+        PokerHand::StraightFlush => "Neptune",
+        PokerHand::FiveOfAKind => "Planet X",
+        PokerHand::FlushHouse => "Ceres",
+        PokerHand::FlushFive => "Eris",
+    }
+}
+
+/// The base Chips and Mult of `hand` at `level` (level 1 is
+/// [`PokerHand::hand_value`]).
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::levels::leveled_hand_value;
+///
+/// assert_eq!(leveled_hand_value(PokerHand::Flush, 1), PokerHand::Flush.hand_value());
+/// assert_eq!(leveled_hand_value(PokerHand::Flush, 4), (80.0, 10.0)); // 35 + 3 x 15, 4 + 3 x 2
+/// ```
+pub fn leveled_hand_value(hand: PokerHand, level: u32) -> (Chips, Mult) {
+    let (chips, mult) = hand.hand_value();
+    let (level_chips, level_mult) = per_level(hand);
+    let extra = level.saturating_sub(1) as f64;
+    (chips + level_chips * extra, mult + level_mult * extra)
+}
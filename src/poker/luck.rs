@@ -0,0 +1,382 @@
+//! # Luck
+//!
+//! Some effects only happen by chance: **Bloodstone** gives x1.5 Mult on 1 in
+//! 2 scored Hearts, **Space Joker** levels up the played hand 1 in 4 times,
+//! a scored Lucky card gives +20 Mult 1 in 5 times and $20 1 in 15 times,
+//! and a scored Glass card shatters 1 in 4 times. Every such roll goes
+//! through a [`Luck`], which either:
+//!
+//! - draws each outcome from a [`SeededRng`], so one seed always gives the
+//!   same outcome ([`Luck::seeded`]), or
+//! - walks through every combination of outcomes, one scoring at a time, so
+//!   the [`ExpectedScore`] can be computed exactly ([`Luck::enumerating`]).
+//!
+//! Only rolls that can change the score are enumerated: a Glass card that
+//! shatters or a Lucky card that wins $20 ([`Luck::roll_unscored`]) never
+//! does in an enumerated outcome.
+//!
+//! **Oops! All 6s** doubles the odds of every roll (see
+//! [`JokerContext::roll`](crate::poker::jokers::JokerContext::roll)).
+
+use crate::error::OrtalabError;
+use crate::poker::{big_score::BigScore, breakdown::ScoreBreakdown, rng::SeededRng};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display, Formatter},
+};
+
+/// The seed used when no seed is given.
+pub const DEFAULT_SEED: u64 = 0;
+
+/// Where the outcome of every random event of a round comes from.
+///
+/// Rolling only needs a shared reference, so a `Luck` can be handed to every
+/// Joker through the [`JokerContext`](crate::poker::jokers::JokerContext).
+///
+/// # Example
+/// ```
+/// use ortalab::poker::luck::Luck;
+///
+/// let (a, b) = (Luck::seeded(7), Luck::seeded(7));
+/// let rolls = |luck: &Luck| (0..20).map(|_| luck.roll(1, 4)).collect::<Vec<_>>();
+/// assert_eq!(rolls(&a), rolls(&b));
+///
+/// // Certain and impossible events do not roll at all
+/// assert!(a.roll(4, 4));
+/// assert!(!a.roll(0, 4));
+/// ```
+#[derive(Debug)]
+pub struct Luck {
+    source: RefCell<Source>,
+}
+
+#[derive(Debug)]
+enum Source {
+    Seeded(SeededRng),
+    Enumerating(Branch),
+}
+
+/// The outcomes enumerated from one checkpoint of a scoring to the next.
+#[derive(Clone, Debug)]
+struct Branch {
+    /// The rolls of the scoring in progress: replayed, then enumerated.
+    rolls: Vec<Roll>,
+    /// How many rolls the scoring in progress used.
+    next: usize,
+    /// How many leading rolls are replayed unchanged (those before `start`).
+    fixed: usize,
+    /// The checkpoint the branch starts at.
+    start: usize,
+    /// How many checkpoints the scoring in progress passed.
+    passed: usize,
+    /// The state at the checkpoint after `start`, once reached.
+    end: Option<u64>,
+    /// The probability of reaching `start` (of every merged path to it).
+    weight: f64,
+}
+
+impl Branch {
+    /// The branch starting at checkpoint `start` after `rolls`.
+    fn new(rolls: Vec<Roll>, start: usize, weight: f64) -> Self {
+        Self {
+            fixed: rolls.len(),
+            rolls,
+            next: 0,
+            start,
+            passed: 0,
+            end: None,
+            weight,
+        }
+    }
+}
+
+/// One roll of an enumerated outcome.
+#[derive(Clone, Copy, Debug)]
+struct Roll {
+    /// The chance the event happens.
+    chance: f64,
+    happened: bool,
+}
+
+impl Roll {
+    /// The probability of this roll's outcome.
+    fn probability(&self) -> f64 {
+        match self.happened {
+            true => self.chance,
+            false => 1.0 - self.chance,
+        }
+    }
+}
+
+impl Luck {
+    /// Draws every outcome from a generator seeded with `seed`.
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            source: RefCell::new(Source::Seeded(SeededRng::new(seed))),
+        }
+    }
+
+    /// Walks through every combination of outcomes, starting with every
+    /// event failing. Score once per outcome, moving on with
+    /// [`next_outcome`](Self::next_outcome).
+    pub fn enumerating() -> Self {
+        Self::branch(Branch::new(Vec::new(), 0, 1.0))
+    }
+
+    fn branch(branch: Branch) -> Self {
+        Self {
+            source: RefCell::new(Source::Enumerating(branch)),
+        }
+    }
+
+    /// Whether an event with `numerator` in `denominator` odds happens.
+    ///
+    /// Odds of at least `denominator` in `denominator` always happen, and
+    /// odds of 0 never do; neither consumes a roll.
+    pub fn roll(&self, numerator: u32, denominator: u32) -> bool {
+        if numerator >= denominator {
+            return true;
+        }
+        if numerator == 0 {
+            return false;
+        }
+
+        match &mut *self.source.borrow_mut() {
+            Source::Seeded(rng) => rng.below(denominator as usize) < numerator as usize,
+            Source::Enumerating(branch) => {
+                if branch.next == branch.rolls.len() {
+                    // Rolls past the branch's end are left to the next branch
+                    if branch.end.is_some() {
+                        return false;
+                    }
+                    // Rolls past the replayed ones start out failing
+                    branch.rolls.push(Roll {
+                        chance: f64::from(numerator) / f64::from(denominator),
+                        happened: false,
+                    });
+                }
+                branch.next += 1;
+                branch.rolls[branch.next - 1].happened
+            }
+        }
+    }
+
+    /// Like [`roll`](Self::roll), for an event that never changes the score.
+    ///
+    /// Enumerating, it never happens, so outcomes only differ where their
+    /// scores can.
+    pub fn roll_unscored(&self, numerator: u32, denominator: u32) -> bool {
+        if matches!(&*self.source.borrow(), Source::Enumerating(_)) {
+            return false;
+        }
+        self.roll(numerator, denominator)
+    }
+
+    /// Marks a point of a scoring where `state`, a hash of everything the
+    /// rest of the scoring depends on, is known.
+    ///
+    /// [`ExpectedScore::enumerate`] scores the outcomes that reach a
+    /// checkpoint in the same state only once.
+    pub fn checkpoint(&self, state: u64) {
+        let Source::Enumerating(branch) = &mut *self.source.borrow_mut() else {
+            return;
+        };
+        branch.passed += 1;
+        if branch.passed == branch.start + 1 {
+            branch.end = Some(state);
+        }
+    }
+
+    /// The probability of the outcome rolled so far (1 when seeded).
+    pub fn probability(&self) -> f64 {
+        match &*self.source.borrow() {
+            Source::Seeded(_) => 1.0,
+            Source::Enumerating(branch) => {
+                let enumerated = &branch.rolls[branch.fixed..branch.next];
+                branch.weight * enumerated.iter().map(Roll::probability).product::<f64>()
+            }
+        }
+    }
+
+    /// The state the last scoring reached the checkpoint after its branch's
+    /// start in, and the branch from there on with `weight`; `None` if the
+    /// scoring finished first.
+    fn next_branch(&self, weight: f64) -> Option<(u64, Branch)> {
+        let Source::Enumerating(branch) = &*self.source.borrow() else {
+            return None;
+        };
+        let rolls = branch.rolls[..branch.next].to_vec();
+        branch
+            .end
+            .map(|state| (state, Branch::new(rolls, branch.start + 1, weight)))
+    }
+
+    /// Moves an enumerating `Luck` on to the next combination of outcomes.
+    ///
+    /// # Returns
+    /// `false` once every combination has been rolled (and always when
+    /// seeded).
+    pub fn next_outcome(&self) -> bool {
+        let Source::Enumerating(branch) = &mut *self.source.borrow_mut() else {
+            return false;
+        };
+
+        // Rolls the last scoring did not reach no longer apply
+        branch.rolls.truncate(branch.next);
+        branch.next = 0;
+        branch.passed = 0;
+        branch.end = None;
+        // Only the rolls enumerated since the branch's start may change
+        let fixed = branch.fixed;
+        while branch.rolls.len() > fixed && branch.rolls.last().is_some_and(|roll| roll.happened) {
+            branch.rolls.pop();
+        }
+        match branch.rolls[fixed..].last_mut() {
+            Some(roll) => {
+                roll.happened = true;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for Luck {
+    fn default() -> Self {
+        Self::seeded(DEFAULT_SEED)
+    }
+}
+
+/// Every outcome of a round's random effects, with its probability.
+///
+/// Its [`Display`] lists the chance of each distinct score, then the
+/// expected score.
+#[derive(Debug, Default)]
+pub struct ExpectedScore {
+    /// Every distinct breakdown, with the probability of scoring it.
+    pub outcomes: Vec<(f64, ScoreBreakdown)>,
+}
+
+impl ExpectedScore {
+    /// Scores every outcome of a round with `score`, which rolls every
+    /// chance with the [`Luck`] it is given.
+    ///
+    /// The outcomes that reach a [`checkpoint`](Luck::checkpoint) in the
+    /// same state are scored on from there only once, and outcomes with the
+    /// same breakdown are merged.
+    ///
+    /// # Errors
+    /// Returns the first error of `score`, and
+    /// [`OrtalabError::TooManyOutcomes`] once `score` has run `limit` times
+    /// with outcomes left to score.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalab::poker::breakdown::ScoreBreakdown;
+    /// use ortalab::poker::luck::ExpectedScore;
+    /// use ortalab::poker::round::ScoringRound;
+    /// use ortalab::poker::score_breakdown;
+    ///
+    /// let round: ScoringRound = serde_yaml::from_str("cards_played: [A♠]").unwrap();
+    /// let breakdown = score_breakdown(round).unwrap();
+    ///
+    /// // Twenty 1 in 2 rolls, of which only the number that happen matters
+    /// let expected = ExpectedScore::enumerate(1_000, |luck| {
+    ///     let mut hits = 0;
+    ///     for _ in 0..20 {
+    ///         hits += u32::from(luck.roll(1, 2));
+    ///         luck.checkpoint(hits.into());
+    ///     }
+    ///     Ok(ScoreBreakdown { hand_level: hits, ..breakdown.clone() })
+    /// })
+    /// .unwrap();
+    /// assert_eq!(expected.outcomes.len(), 21);
+    /// assert_eq!(expected.outcomes[0].0, 0.5f64.powi(20));
+    /// ```
+    pub fn enumerate(
+        limit: usize,
+        mut score: impl FnMut(&Luck) -> Result<ScoreBreakdown, OrtalabError>,
+    ) -> Result<Self, OrtalabError> {
+        let mut expected = Self::default();
+        let mut branches = vec![Branch::new(Vec::new(), 0, 1.0)];
+        let mut scorings = 0;
+
+        while !branches.is_empty() {
+            // The branches from the next checkpoint, one per state
+            let mut next_branches: Vec<Branch> = Vec::new();
+            let mut states = HashMap::new();
+
+            for branch in branches {
+                let luck = Luck::branch(branch);
+                loop {
+                    if scorings == limit {
+                        return Err(OrtalabError::TooManyOutcomes { limit });
+                    }
+                    let breakdown = score(&luck)?;
+                    scorings += 1;
+
+                    let probability = luck.probability();
+                    match luck.next_branch(probability) {
+                        Some((state, branch)) => match states.get(&state) {
+                            Some(&index) => {
+                                let merged: &mut Branch = &mut next_branches[index];
+                                merged.weight += probability;
+                            }
+                            None => {
+                                states.insert(state, next_branches.len());
+                                next_branches.push(branch);
+                            }
+                        },
+                        None => expected.add(probability, breakdown),
+                    }
+
+                    if !luck.next_outcome() {
+                        break;
+                    }
+                }
+            }
+            branches = next_branches;
+        }
+
+        Ok(expected)
+    }
+
+    /// Adds an outcome, merged into an equal one if there is one.
+    fn add(&mut self, probability: f64, breakdown: ScoreBreakdown) {
+        match self
+            .outcomes
+            .iter_mut()
+            .find(|(_, other)| *other == breakdown)
+        {
+            Some((merged, _)) => *merged += probability,
+            None => self.outcomes.push((probability, breakdown)),
+        }
+    }
+
+    /// The expected score: every outcome's score weighted by its probability.
+    pub fn mean(&self) -> f64 {
+        self.outcomes
+            .iter()
+            .map(|(probability, breakdown)| probability * breakdown.score.to_f64())
+            .sum()
+    }
+
+    /// The probability of each distinct score, lowest first.
+    pub fn distribution(&self) -> BTreeMap<BigScore, f64> {
+        let mut distribution = BTreeMap::new();
+        for (probability, breakdown) in &self.outcomes {
+            *distribution.entry(breakdown.score.clone()).or_default() += probability;
+        }
+        distribution
+    }
+}
+
+impl Display for ExpectedScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (score, probability) in self.distribution() {
+            writeln!(f, "{:>7.3}%  {score}", probability * 100.0)?;
+        }
+        writeln!(f, "expected: {:.2}", self.mean())
+    }
+}
@@ -3,36 +3,84 @@
 //! This module contains all the logic for evaluating and scoring poker rounds.
 //! It is organized into several submodules:
 //!
+//! - [`arrangement`] — finds the best order for the Jokers in play.
+//! - [`big_score`] — the exact final score, however large it grows.
+//! - [`blinds`] — boss blinds that debuff cards or halve the base score.
+//! - [`breakdown`] — the stage‑by‑stage result of scoring a round.
+//! - [`cards`] — card notation, with Ortalab's extra enhancements and seals.
+//! - [`context`] — the game state a round is played in (money, hands, discards).
+//! - [`deck`] — draw pile, hand and discard pile, for simulating real play.
+//! - [`enhancements`] — the Stone, Gold and Lucky enhancements.
+//! - [`explain`] — step‑by‑step scoring trace used by `--explain`.
 //! - [`hands`] — functions for determining poker hands and card order.
 //! - [`helpers`] — utility functions for suits, colors, and related calculations.
-//! - [`jokers`] — logic for applying joker effects to hands and scores.
+//! - [`joker_slot`] — Jokers in play, including Ortalab‑only Jokers like Brainstorm.
+//! - [`joker_effects`] — the effect of every built‑in Joker.
+//! - [`jokers`] — the Joker effect trait and registry, and the Joker phases of scoring.
+//! - [`levels`] — hand levels from planet cards.
+//! - [`luck`] — seeded or enumerated outcomes of random effects.
 //! - [`modifiers`] — functions for applying scoring modifiers and enhancements.
+//! - [`number`] — the arithmetic scoring runs in: `f64`, or exact.
+//! - [`resolution`] — which ability each Joker slot uses (Blueprint, Brainstorm).
+//! - [`retriggers`] — how often each card triggers (Mime, Sock and Buskin).
+//! - [`rng`] — the seeded random number generator.
+//! - [`round`] — the round being scored.
 //! - [`scoring`] — the main scoring pipeline, including [`score`].
+//! - [`seals`] — card seals and what they give at the end of the round.
+//! - [`simulation`] — seeded Monte Carlo estimates of a Joker build's score.
+//! - [`solver`] — finds the best cards to play from a whole hand.
+//! - [`validation`] — checks that a round is legal before it is scored.
 //!
 //! ## Example
 //! ```
-//! use ortalib::poker::{determine_poker_hand, score};
-//! use ortalib::Round;
+//! use ortalab::poker::score;
+//! use ortalib::{Card, Rank, Round, Suit};
 //!
 //! // Construct a dummy round (fill with real cards in practice)
 //! let round = Round {
-//!     cards_played: vec![A♥,K♠,8♦,6♣,4♥],
+//!     cards_played: vec![
+//!         Card::new(Rank::Ace, Suit::Hearts, None, None),
+//!         Card::new(Rank::King, Suit::Spades, None, None),
+//!     ],
 //!     cards_held_in_hand: vec![],
 //!     jokers: vec![],
 //! };
 //!
-//! let (chips, mult) = score(round);
+//! let (chips, mult) = score(round).unwrap();
 //! assert!(chips >= 0.0);
 //! assert!(mult >= 1.0);
 //! ```
 
+pub mod arrangement;
+pub mod big_score;
+pub mod blinds;
+pub mod breakdown;
+pub mod cards;
+pub mod context;
+pub mod deck;
+pub mod enhancements;
+pub mod explain;
 pub mod hands;
 pub mod helpers;
+pub mod joker_effects;
+pub mod joker_slot;
 pub mod jokers;
+pub mod levels;
+pub mod luck;
 pub mod modifiers;
+pub mod number;
+pub mod resolution;
+pub mod retriggers;
+pub mod rng;
+pub mod round;
 pub mod scoring;
+pub mod seals;
+pub mod simulation;
+pub mod solver;
+pub mod validation;
 
 pub use hands::{compute_card_order, determine_poker_hand};
 pub use helpers::{compute_most_appear_suit, determine_current_suit, determine_total_colors};
 pub use modifiers::apply_edition;
-pub use scoring::score;
+pub use scoring::{score, score_breakdown, score_explained, score_with_registry};
+pub use validation::validate;
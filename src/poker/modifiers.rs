@@ -6,10 +6,16 @@
 //! pipeline accordingly.
 //!
 //! - [`apply_enhancement`] — applies a single [`Enhancement`] to chip/mult values.
+//! - [`apply_extra_enhancement`] — applies a Stone, Gold or Lucky enhancement
+//!   to chip/mult values.
 //! - [`apply_edition`] — applies a single [`Edition`] to chip/mult values.
 //! - [`compute_enhancement`] — applies all enhancements and editions across a set of cards.
 
-use ortalib::{Card, Chips, Edition, Enhancement, Mult};
+use crate::poker::{
+    cards::PlayingCard, enhancements::ExtraEnhancement, explain::Explain, jokers::JokerContext,
+    number::Number,
+};
+use ortalib::{Edition, Enhancement};
 
 /// Applies a single [`Enhancement`] to the given chip and multiplier values.
 ///
@@ -34,12 +40,12 @@ use ortalib::{Card, Chips, Edition, Enhancement, Mult};
 /// assert_eq!(chips, 130.0);
 /// assert_eq!(mult, 1.0);
 /// ```
-pub fn apply_enhancement(
+pub fn apply_enhancement<N: Number>(
     enhancement: Enhancement,
-    input_chip: f64,
-    input_mul: f64,
+    input_chip: N,
+    input_mul: N,
     in_hand: bool,
-) -> (Chips, Mult) {
+) -> (N, N) {
     let (mut chip, mut mul) = (input_chip, input_mul);
 
     if !in_hand {
@@ -57,6 +63,59 @@ pub fn apply_enhancement(
     (chip, mul)
 }
 
+/// Applies a scored card's [`ExtraEnhancement`] to the given chip and
+/// multiplier values.
+///
+/// A Stone card gives **+50 Chips**, and a Lucky card has a 1 in 5 chance
+/// (rolled through `ctx`) to give **+20 Mult**. A Gold card only pays out
+/// when held at the end of the round.
+///
+/// # Arguments
+/// * `enhancement` — The enhancement to apply.
+/// * `input_chip` — Current chip value.
+/// * `input_mul` — Current multiplier value.
+/// * `ctx` — The round, to roll a Lucky card's chance.
+///
+/// # Returns
+/// A tuple `(Chips, Mult)` with updated values.
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::enhancements::ExtraEnhancement;
+/// use ortalab::poker::jokers::JokerContext;
+/// use ortalab::poker::modifiers::apply_extra_enhancement;
+///
+/// let ctx = JokerContext::new(PokerHand::HighCard, &[], &[], &[]);
+/// let (chips, mult) = apply_extra_enhancement(ExtraEnhancement::Stone, 100.0, 1.0, &ctx);
+/// assert_eq!(chips, 150.0);
+/// assert_eq!(mult, 1.0);
+///
+/// // Without luck, a Lucky card never hits
+/// let (chips, mult) = apply_extra_enhancement(ExtraEnhancement::Lucky, 100.0, 1.0, &ctx);
+/// assert_eq!((chips, mult), (100.0, 1.0));
+/// ```
+pub fn apply_extra_enhancement<N: Number>(
+    enhancement: ExtraEnhancement,
+    input_chip: N,
+    input_mul: N,
+    ctx: &JokerContext,
+) -> (N, N) {
+    let (mut chip, mut mul) = (input_chip, input_mul);
+
+    match enhancement {
+        ExtraEnhancement::Stone => chip += 50.0,
+        ExtraEnhancement::Gold => {}
+        ExtraEnhancement::Lucky => {
+            if ctx.roll(1, 5) {
+                mul += 20.0;
+            }
+        }
+    }
+
+    (chip, mul)
+}
+
 /// Applies a single [`Edition`] to the given chip and multiplier values.
 ///
 /// Editions only apply when the card is in play (`in_hand == false`).
@@ -79,12 +138,12 @@ pub fn apply_enhancement(
 /// assert_eq!(chips, 150.0);
 /// assert_eq!(mult, 1.0);
 /// ```
-pub fn apply_edition(
+pub fn apply_edition<N: Number>(
     edition: Edition,
-    input_chip: f64,
-    input_mul: f64,
+    input_chip: N,
+    input_mul: N,
     in_hand: bool,
-) -> (Chips, Mult) {
+) -> (N, N) {
     let (mut chip, mut mul) = (input_chip, input_mul);
 
     if !in_hand {
@@ -100,13 +159,15 @@ pub fn apply_edition(
 /// Applies all enhancements and editions across a slice of cards.
 ///
 /// Each card may have an [`Enhancement`] and/or an [`Edition`]. Both are
-/// applied in sequence to update the chip and multiplier values.
+/// applied in sequence to update the chip and multiplier values. Every
+/// enhancement or edition that changes the score is recorded in `explain`.
 ///
 /// # Arguments
 /// * `cards` — The cards to process.
 /// * `chip` — Starting chip value.
 /// * `mul` — Starting multiplier value.
 /// * `in_hand` — Whether the cards are in hand or in play.
+/// * `explain` — Trace that receives one step per effect that fired.
 ///
 /// # Returns
 /// A tuple `(Chips, Mult)` with updated values.
@@ -114,31 +175,53 @@ pub fn apply_edition(
 /// # Example
 /// ```
 /// use ortalib::{Card, Rank, Suit, Enhancement};
+/// use ortalab::poker::explain::Explain;
 /// use ortalab::poker::modifiers::compute_enhancement;
+/// use ortalab::poker::cards::PlayingCard;
 ///
-/// let mut card = Card::new(Rank::Ace, Suit::Spades, None, None);
-/// card.enhancement = Some(Enhancement::Bonus);
+/// let card = PlayingCard::from(Card::new(Rank::Ace, Suit::Spades, Some(Enhancement::Bonus), None));
 ///
-/// let (chips, mult) = compute_enhancement(&[card], 100.0, 1.0, false);
+/// let mut explain = Explain::new(true);
+/// let (chips, mult) = compute_enhancement(&[card], 100.0, 1.0, false, &mut explain);
 /// assert_eq!(chips, 130.0);
 /// assert_eq!(mult, 1.0);
+/// assert_eq!(explain.steps().len(), 1);
 /// ```
-pub fn compute_enhancement(cards: &[Card], chip: f64, mul: f64, in_hand: bool) -> (Chips, Mult) {
-    let (mut new_chip, mut new_mul) = (chip, mul);
+pub fn compute_enhancement<N: Number>(
+    cards: &[PlayingCard],
+    chip: N,
+    mul: N,
+    in_hand: bool,
+    explain: &mut Explain,
+) -> (N, N) {
+    let mut result = (chip, mul);
+    let location = if in_hand { "held" } else { "scored" };
 
-    cards.iter().for_each(|card| {
+    for card in cards {
         if let Some(enhancement) = card.enhancement {
-            let result = apply_enhancement(enhancement, new_chip, new_mul, in_hand);
-            new_chip = result.0;
-            new_mul = result.1;
+            let (chip, mul) = result.clone();
+            let enhanced = apply_enhancement(enhancement, chip, mul, in_hand);
+            if enhanced != result {
+                explain.record(
+                    || format!("{location} {}{} {enhancement}", card.rank, card.suit),
+                    &enhanced,
+                );
+            }
+            result = enhanced;
         }
 
         if let Some(edition) = card.edition {
-            let result = apply_edition(edition, new_chip, new_mul, in_hand);
-            new_chip = result.0;
-            new_mul = result.1;
+            let (chip, mul) = result.clone();
+            let edited = apply_edition(edition, chip, mul, in_hand);
+            if edited != result {
+                explain.record(
+                    || format!("{location} {}{} {edition}", card.rank, card.suit),
+                    &edited,
+                );
+            }
+            result = edited;
         }
-    });
+    }
 
-    (new_chip, new_mul)
+    result
 }
@@ -0,0 +1,225 @@
+//! # Numbers
+//!
+//! The scoring pipeline is generic over the [`Number`] its chips and mult
+//! are kept in. [`score`](crate::poker::score) uses `f64`, like `ortalib`'s
+//! [`Chips`](ortalib::Chips) and [`Mult`](ortalib::Mult).
+//! [`score_exact`](crate::poker::scoring::score_exact) uses [`Exact`], which
+//! never rounds: every value the game adds or multiplies by (`+4`, `x1.5`,
+//! …) is a dyadic rational, a whole number times a power of two, and so is
+//! every sum and product of them.
+
+use crate::poker::big_score::{BigScore, dyadic_to_f64};
+use num_bigint::BigUint;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+/// A number the scoring pipeline can run in.
+///
+/// Effects only ever add or multiply by plain `f64` amounts, so that is all
+/// a `Number` has to support.
+pub trait Number:
+    Clone
+    + Debug
+    + PartialEq
+    + Add<f64, Output = Self>
+    + Mul<f64, Output = Self>
+    + AddAssign<f64>
+    + MulAssign<f64>
+    + 'static
+{
+    /// `value` as this number.
+    fn from_f64(value: f64) -> Self;
+
+    /// The nearest `f64`.
+    fn to_f64(&self) -> f64;
+
+    /// `chips × mult` rounded down, or `None` if it cannot be computed.
+    fn floor_score(chips: Self, mult: Self) -> Option<BigScore>;
+
+    /// Feeds the number to `state`, so that equal numbers hash the same.
+    fn hash_value<H: Hasher>(&self, state: &mut H);
+}
+
+impl Number for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn floor_score(chips: Self, mult: Self) -> Option<BigScore> {
+        BigScore::from_product(chips, mult)
+    }
+
+    fn hash_value<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+/// An exact, non‑negative dyadic rational, `significand × 2^exponent`.
+///
+/// The significand is an arbitrary‑precision integer, so no sum or product
+/// ever rounds or overflows. Only a negative or non‑finite input is
+/// *unrepresentable*; it stays so through every later operation, and its
+/// score is `None`.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::number::{Exact, Number};
+///
+/// // 1.5^40 needs 64 significant bits: f64 rounds it, Exact does not
+/// let mut float = 1.0;
+/// let mut exact = Exact::from_f64(1.0);
+/// for _ in 0..40 {
+///     float *= 1.5;
+///     exact *= 1.5;
+/// }
+/// assert_ne!(Exact::from_f64(float), exact);
+/// assert_eq!((exact.clone() * 2f64.powi(40)).to_string(), "12157665459056928801"); // 3^40
+///
+/// // 1.5^500 needs 793 bits, and still does not round
+/// for _ in 40..500 {
+///     exact *= 1.5;
+/// }
+/// let score = Exact::floor_score(exact * 2f64.powi(500), Exact::from_f64(1.0)).unwrap();
+/// assert!(format!("{score:#}").ends_with("0001")); // 3^500
+///
+/// let value = Exact::from_f64(2.0) * 1.5 + 0.25;
+/// assert_eq!(value.to_string(), "3.25");
+/// assert!(!Exact::from_f64(f64::NAN).is_representable());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exact {
+    /// `(significand, exponent)` with an odd significand, `(0, 0)` for zero,
+    /// or `None` once unrepresentable.
+    value: Option<(BigUint, i64)>,
+}
+
+impl Exact {
+    const UNREPRESENTABLE: Self = Self { value: None };
+
+    fn new(significand: BigUint, exponent: i64) -> Self {
+        let Some(zeros) = significand.trailing_zeros() else {
+            return Self {
+                value: Some((BigUint::ZERO, 0)),
+            };
+        };
+        Self {
+            value: Some((significand >> zeros, exponent + zeros as i64)),
+        }
+    }
+
+    /// Whether the value is a number at all (see [`Exact`]).
+    pub fn is_representable(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+impl Number for Exact {
+    fn from_f64(value: f64) -> Self {
+        if !value.is_finite() || value < 0.0 {
+            return Self::UNREPRESENTABLE;
+        }
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        match exponent {
+            0 => Self::new(fraction.into(), -1074),
+            _ => Self::new((fraction | (1 << 52)).into(), exponent - 1075),
+        }
+    }
+
+    fn to_f64(&self) -> f64 {
+        match &self.value {
+            Some((significand, exponent)) => dyadic_to_f64(significand, *exponent),
+            None => f64::NAN,
+        }
+    }
+
+    fn floor_score(chips: Self, mult: Self) -> Option<BigScore> {
+        let ((a, a_exponent), (b, b_exponent)) = (chips.value?, mult.value?);
+        Some(BigScore::from_dyadic(a * b, a_exponent + b_exponent))
+    }
+
+    fn hash_value<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+impl Add for Exact {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (Some((a, a_exponent)), Some((b, b_exponent))) = (self.value, other.value) else {
+            return Self::UNREPRESENTABLE;
+        };
+
+        // Line both up on the smaller exponent, then add
+        let exponent = a_exponent.min(b_exponent);
+        let a = a << (a_exponent - exponent).unsigned_abs();
+        let b = b << (b_exponent - exponent).unsigned_abs();
+        Self::new(a + b, exponent)
+    }
+}
+
+impl Mul for Exact {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let (Some((a, a_exponent)), Some((b, b_exponent))) = (self.value, other.value) else {
+            return Self::UNREPRESENTABLE;
+        };
+        Self::new(a * b, a_exponent + b_exponent)
+    }
+}
+
+impl Add<f64> for Exact {
+    type Output = Self;
+
+    fn add(self, other: f64) -> Self {
+        self + Self::from_f64(other)
+    }
+}
+
+impl Mul<f64> for Exact {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        self * Self::from_f64(other)
+    }
+}
+
+impl AddAssign<f64> for Exact {
+    fn add_assign(&mut self, other: f64) {
+        *self = std::mem::replace(self, Self::UNREPRESENTABLE) + other;
+    }
+}
+
+impl MulAssign<f64> for Exact {
+    fn mul_assign(&mut self, other: f64) {
+        *self = std::mem::replace(self, Self::UNREPRESENTABLE) * other;
+    }
+}
+
+/// Prints every digit; a dyadic rational always has a finite decimal
+/// expansion.
+impl Display for Exact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some((significand, exponent)) = &self.value else {
+            return f.pad("unrepresentable");
+        };
+        if *exponent >= 0 {
+            return f.pad(&BigScore::from_dyadic(significand.clone(), *exponent).digits());
+        }
+
+        // significand / 2^k = significand × 5^k / 10^k
+        let places = exponent.unsigned_abs() as usize;
+        let digits = (significand * BigUint::from(5u32).pow(places as u32)).to_string();
+        let digits = format!("{digits:0>width$}", width = places + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - places);
+        f.pad(&format!("{whole}.{fraction}"))
+    }
+}
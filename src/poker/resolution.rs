@@ -0,0 +1,110 @@
+//! # Joker Resolution
+//!
+//! Copying Jokers act with another Joker's ability:
+//!
+//! - **Blueprint** copies the Joker to its right.
+//! - **Brainstorm** copies the leftmost Joker.
+//!
+//! Before any Joker is applied, each slot is resolved to the ability it
+//! actually uses. Copies are followed through chains
+//! (Blueprint → Blueprint → Baron acts as Baron), while cycles
+//! (Blueprint → Brainstorm → Blueprint) and copies of Jokers with nothing to
+//! copy resolve to no ability at all.
+
+use crate::poker::{
+    joker_effects::{BRAINSTORM, OOPS_ALL_SIXES},
+    joker_slot::{JokerKind, JokerSlot},
+};
+use ortalib::Joker;
+use std::collections::HashSet;
+
+/// `ortalib` Jokers whose effect is a rule change rather than an ability.
+/// Copying Jokers cannot copy these, nor Oops! All 6s.
+const PASSIVE_JOKERS: [Joker; 5] = [
+    Joker::FourFingers,
+    Joker::Shortcut,
+    Joker::Pareidolia,
+    Joker::Splash,
+    Joker::SmearedJoker,
+];
+
+/// Resolves the ability used by the Joker in slot `position`.
+///
+/// # Returns
+/// The [`JokerKind`] whose ability the slot uses, or `None` if the slot copies
+/// nothing (no Joker to copy, a cycle, or a Joker with no copyable effect).
+///
+/// # Example
+/// ```
+/// use ortalib::Joker;
+/// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+/// use ortalab::poker::resolution::resolve_joker;
+///
+/// let jokers = vec![
+///     JokerSlot::new(Joker::Blueprint, None),
+///     JokerSlot::new(Joker::Blueprint, None),
+///     JokerSlot::new(Joker::Baron, None),
+/// ];
+/// assert_eq!(resolve_joker(&jokers, 0), Some(Joker::Baron.into()));
+///
+/// let cycle = vec![
+///     JokerSlot::new(Joker::Blueprint, None),
+///     JokerSlot::new(JokerKind::custom("Brainstorm"), None),
+/// ];
+/// assert_eq!(resolve_joker(&cycle, 0), None);
+/// assert_eq!(resolve_joker(&cycle, 1), None);
+///
+/// let passive = vec![
+///     JokerSlot::new(Joker::Blueprint, None),
+///     JokerSlot::new(Joker::Splash, None),
+/// ];
+/// assert_eq!(resolve_joker(&passive, 0), None);
+/// ```
+pub fn resolve_joker(jokers: &[JokerSlot], position: usize) -> Option<JokerKind> {
+    let mut visited = HashSet::new();
+    let mut current = position;
+
+    loop {
+        if !visited.insert(current) {
+            return None;
+        }
+
+        let joker = &jokers.get(current)?.joker;
+        let target = match joker {
+            JokerKind::Standard(Joker::Blueprint) => current + 1,
+            brainstorm if *brainstorm == BRAINSTORM => 0,
+            passive if current != position && is_passive(passive) => return None,
+            _ => return Some(joker.clone()),
+        };
+        current = target;
+    }
+}
+
+/// Resolves the ability used by every Joker slot, left to right.
+///
+/// # Example
+/// ```
+/// use ortalib::Joker;
+/// use ortalab::poker::joker_slot::{JokerKind, JokerSlot};
+/// use ortalab::poker::resolution::resolve_jokers;
+///
+/// let jokers = vec![
+///     JokerSlot::new(Joker::Mime, None),
+///     JokerSlot::new(JokerKind::custom("Brainstorm"), None),
+/// ];
+/// let mime = Some(JokerKind::from(Joker::Mime));
+/// assert_eq!(resolve_jokers(&jokers), vec![mime.clone(), mime]);
+/// ```
+pub fn resolve_jokers(jokers: &[JokerSlot]) -> Vec<Option<JokerKind>> {
+    (0..jokers.len())
+        .map(|position| resolve_joker(jokers, position))
+        .collect()
+}
+
+/// Whether `joker` only changes the rules, so there is no ability to copy.
+fn is_passive(joker: &JokerKind) -> bool {
+    match joker {
+        JokerKind::Standard(joker) => PASSIVE_JOKERS.contains(joker),
+        custom => *custom == OOPS_ALL_SIXES,
+    }
+}
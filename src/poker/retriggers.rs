@@ -0,0 +1,137 @@
+//! # Retriggers
+//!
+//! Some Jokers make cards trigger more than once:
+//!
+//! - **Mime** retriggers every card held in hand (Steel, Baron, Raised Fist).
+//! - **Sock and Buskin** retriggers every scored face card (rank chips,
+//!   enhancement, edition and every on‑scored Joker effect).
+//!
+//! A card with a **Red** [seal](crate::poker::seals) also triggers once more,
+//! both when scored and when held.
+//!
+//! A retriggered card is represented by repeating it in the list of cards
+//! handed to the scoring pipeline, so every downstream step sees one entry
+//! per trigger. Since [`Card`]s only compare equal to their own copies, a
+//! repeated entry can always be told apart from a different card with the
+//! same rank and suit.
+
+use crate::poker::{
+    cards::PlayingCard, joker_slot::JokerSlot, resolution::resolve_jokers, seals::Seal,
+};
+use ortalib::Joker;
+
+/// Counts how many times a scored card triggers.
+///
+/// A card triggers once, plus once for each *Sock and Buskin* (or copy of
+/// one) if it is a face card (every card is a face card when *Pareidolia* is
+/// present), plus once for a Red seal.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, Rank, Suit};
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::retriggers::scored_triggers;
+/// use ortalab::poker::cards::{PlayingCard, parse_card};
+///
+/// let king = PlayingCard::from(Card::new(Rank::King, Suit::Hearts, None, None));
+/// let five = PlayingCard::from(Card::new(Rank::Five, Suit::Hearts, None, None));
+/// let jokers = vec![JokerSlot::new(Joker::SockAndBuskin, None)];
+///
+/// assert_eq!(scored_triggers(&king, &jokers), 2);
+/// assert_eq!(scored_triggers(&five, &jokers), 1);
+///
+/// let red_king = parse_card("K♥ Red").unwrap();
+/// assert_eq!(scored_triggers(&red_king, &jokers), 3);
+/// ```
+pub fn scored_triggers(card: &PlayingCard, jokers: &[JokerSlot]) -> usize {
+    let is_pareidolia_exists = jokers.iter().any(|j| j.joker == Joker::Pareidolia);
+    let sock_and_buskin = resolve_jokers(jokers)
+        .into_iter()
+        .filter(|ability| *ability == Some(Joker::SockAndBuskin.into()))
+        .count();
+
+    let is_face = !card.is_stone() && (card.rank.is_face() || is_pareidolia_exists);
+    let sock_and_buskin = match is_face {
+        true => sock_and_buskin,
+        false => 0,
+    };
+    1 + sock_and_buskin + red_seal(card)
+}
+
+/// One retrigger for a Red seal.
+fn red_seal(card: &PlayingCard) -> usize {
+    usize::from(card.seal == Some(Seal::Red))
+}
+
+/// Counts how many times a card held in hand triggers: once, plus once per
+/// *Mime* (or copy of one), plus once for a Red seal.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, Rank, Suit};
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::retriggers::held_triggers;
+/// use ortalab::poker::cards::{PlayingCard, parse_card};
+///
+/// let king = PlayingCard::from(Card::new(Rank::King, Suit::Spades, None, None));
+/// let jokers = vec![JokerSlot::new(Joker::Mime, None)];
+/// assert_eq!(held_triggers(&king, &jokers), 2);
+/// assert_eq!(held_triggers(&king, &[]), 1);
+///
+/// // A held Steel King with a Red seal triggers twice
+/// let red_steel_king = parse_card("K♠ Steel Red").unwrap();
+/// assert_eq!(held_triggers(&red_steel_king, &[]), 2);
+/// ```
+pub fn held_triggers(card: &PlayingCard, jokers: &[JokerSlot]) -> usize {
+    let mime = resolve_jokers(jokers)
+        .into_iter()
+        .filter(|ability| *ability == Some(Joker::Mime.into()))
+        .count();
+    1 + mime + red_seal(card)
+}
+
+/// Repeats each scored card once per trigger, keeping the original order.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, Rank, Suit};
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::retriggers::expand_scored;
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let cards = vec![
+///     PlayingCard::from(Card::new(Rank::Queen, Suit::Spades, None, None)),
+///     PlayingCard::from(Card::new(Rank::Two, Suit::Spades, None, None)),
+/// ];
+/// let jokers = vec![JokerSlot::new(Joker::SockAndBuskin, None)];
+///
+/// let expanded = expand_scored(&cards, &jokers);
+/// assert_eq!(expanded, vec![cards[0], cards[0], cards[1]]);
+/// ```
+pub fn expand_scored(cards: &[PlayingCard], jokers: &[JokerSlot]) -> Vec<PlayingCard> {
+    cards
+        .iter()
+        .flat_map(|card| std::iter::repeat_n(*card, scored_triggers(card, jokers)))
+        .collect()
+}
+
+/// Repeats each held card once per trigger, keeping the original order.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, Rank, Suit};
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::retriggers::expand_held;
+/// use ortalab::poker::cards::PlayingCard;
+///
+/// let cards = vec![PlayingCard::from(Card::new(Rank::King, Suit::Spades, None, None))];
+/// let jokers = vec![JokerSlot::new(Joker::Mime, None)];
+///
+/// assert_eq!(expand_held(&cards, &jokers).len(), 2);
+/// ```
+pub fn expand_held(cards: &[PlayingCard], jokers: &[JokerSlot]) -> Vec<PlayingCard> {
+    cards
+        .iter()
+        .flat_map(|card| std::iter::repeat_n(*card, held_triggers(card, jokers)))
+        .collect()
+}
@@ -0,0 +1,223 @@
+//! # Scoring Round
+//!
+//! [`ScoringRound`] is the round Ortalab actually scores. It has the same
+//! shape as [`ortalib::Round`], but its Jokers are [`JokerSlot`]s so that
+//! Ortalab‑only Jokers (e.g. **Brainstorm**) can appear in round files.
+//!
+//! Any [`Round`] converts into a [`ScoringRound`], so code written against
+//! `ortalib` keeps working with [`score`](crate::poker::score).
+//!
+//! Round files may also set `joker_slots`, the number of Joker slots the
+//! player has (5 by default), which [`validate`](crate::poker::validation::validate)
+//! checks the Jokers against, the `hand_levels` of each poker hand (see
+//! [`levels`](crate::poker::levels)), the game state in `context` (see
+//! [`context`](crate::poker::context)), the boss `blind` (see
+//! [`blinds`](crate::poker::blinds)), and the `expected_score` and
+//! `expected_hand` that `ortalab test` checks the scoring against. Any other
+//! key is rejected, so a misspelled one cannot be silently ignored.
+
+use crate::poker::{
+    big_score::BigScore,
+    blinds::BossBlind,
+    cards::{PlayingCard, deserialize_cards},
+    context::RoundContext,
+    joker_slot::JokerSlot,
+};
+use ortalib::{PokerHand, Round};
+use serde::{Deserialize, Deserializer, de::Error};
+use std::collections::HashMap;
+
+/// The inputs of one scoring round.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::big_score::BigScore;
+/// use ortalab::poker::joker_slot::JokerKind;
+/// use ortalab::poker::round::ScoringRound;
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [K♥, K♠]\njokers: [Brainstorm, Baron]",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(round.cards_played.len(), 2);
+/// assert!(round.cards_held_in_hand.is_empty());
+/// assert_eq!(round.jokers[0].joker, JokerKind::custom("Brainstorm"));
+/// assert_eq!(round.joker_slots, 5);
+/// assert_eq!(round.expected_score, None);
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [K♥, K♠]\nexpected_hand: Pair\nexpected_score: 60",
+/// )
+/// .unwrap();
+/// assert_eq!(round.expected_hand, Some(ortalib::PokerHand::Pair));
+/// assert_eq!(round.expected_score, Some(BigScore::from(60)));
+///
+/// // Scores past 2^53 are recorded exactly
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♥]\nexpected_score: 9007199254740993").unwrap();
+/// assert_eq!(format!("{:#}", round.expected_score.unwrap()), "9007199254740993");
+///
+/// // A misspelled key is an error, not silently ignored
+/// let typo = serde_yaml::from_str::<ScoringRound>("cards_played: [K♥]\nexpected_scor: 1");
+/// assert!(typo.unwrap_err().to_string().starts_with("unknown field `expected_scor`"));
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScoringRound {
+    /// The played cards; like every card in a round file, each may end with
+    /// a [seal](crate::poker::seals) (e.g. `K♠ Steel Red`).
+    #[serde(deserialize_with = "deserialize_cards")]
+    pub cards_played: Vec<PlayingCard>,
+
+    #[serde(default, deserialize_with = "deserialize_cards")]
+    pub cards_held_in_hand: Vec<PlayingCard>,
+
+    #[serde(default)]
+    pub jokers: Vec<JokerSlot>,
+
+    #[serde(default = "default_joker_slots")]
+    pub joker_slots: usize,
+
+    /// The level of each poker hand; hands not listed are level 1.
+    #[serde(default, deserialize_with = "deserialize_hand_levels")]
+    pub hand_levels: HashMap<PokerHand, u32>,
+
+    /// The game state the round is played in.
+    #[serde(default)]
+    pub context: RoundContext,
+
+    /// The boss blind this round is played against, if any.
+    #[serde(default, with = "serde_yaml::with::singleton_map")]
+    pub blind: Option<BossBlind>,
+
+    /// The exact score the round should produce, written in full or in
+    /// scientific notation (see [`BigScore`]).
+    #[serde(default)]
+    pub expected_score: Option<BigScore>,
+
+    #[serde(default, deserialize_with = "deserialize_hand")]
+    pub expected_hand: Option<PokerHand>,
+}
+
+impl ScoringRound {
+    /// The level of `hand` in this round.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalib::PokerHand;
+    /// use ortalab::poker::round::ScoringRound;
+    ///
+    /// let round: ScoringRound =
+    ///     serde_yaml::from_str("cards_played: [A♠]\nhand_levels:\n  Flush: 4").unwrap();
+    /// assert_eq!(round.hand_level(PokerHand::Flush), 4);
+    /// assert_eq!(round.hand_level(PokerHand::Pair), 1);
+    ///
+    /// let zero = serde_yaml::from_str::<ScoringRound>("cards_played: []\nhand_levels: {Pair: 0}");
+    /// assert!(zero.is_err());
+    /// ```
+    pub fn hand_level(&self, hand: PokerHand) -> u32 {
+        self.hand_levels.get(&hand).copied().unwrap_or(1)
+    }
+
+    /// An empty round with `jokers` in play, to fill in with
+    /// struct update syntax.
+    pub fn with_jokers(jokers: Vec<JokerSlot>) -> Self {
+        Self {
+            cards_played: Vec::new(),
+            cards_held_in_hand: Vec::new(),
+            jokers,
+            joker_slots: DEFAULT_JOKER_SLOTS,
+            hand_levels: HashMap::new(),
+            context: RoundContext::default(),
+            blind: None,
+            expected_score: None,
+            expected_hand: None,
+        }
+    }
+}
+
+/// The number of Joker slots a player starts with.
+pub const DEFAULT_JOKER_SLOTS: usize = 5;
+
+fn default_joker_slots() -> usize {
+    DEFAULT_JOKER_SLOTS
+}
+
+/// Every poker hand, from weakest to strongest.
+const POKER_HANDS: [PokerHand; 12] = [
+    PokerHand::HighCard,
+    PokerHand::Pair,
+    PokerHand::TwoPair,
+    PokerHand::ThreeOfAKind,
+    PokerHand::Straight,
+    PokerHand::Flush,
+    PokerHand::FullHouse,
+    PokerHand::FourOfAKind,
+    PokerHand::StraightFlush,
+    PokerHand::FiveOfAKind,
+    PokerHand::FlushHouse,
+    PokerHand::FlushFive,
+];
+
+/// Parses a poker hand by its displayed name, ignoring case
+/// (e.g. `Three Of A Kind` or `three of a kind`).
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::round::parse_poker_hand;
+///
+/// assert_eq!(parse_poker_hand("flush house"), Some(PokerHand::FlushHouse));
+/// assert_eq!(parse_poker_hand("Royal Flush"), None);
+/// ```
+pub fn parse_poker_hand(name: &str) -> Option<PokerHand> {
+    POKER_HANDS
+        .into_iter()
+        .find(|hand| hand.to_string().eq_ignore_ascii_case(name.trim()))
+}
+
+fn deserialize_hand<'de, D>(deserializer: D) -> Result<Option<PokerHand>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+    parse_poker_hand(&name)
+        .map(Some)
+        .ok_or_else(|| D::Error::custom(format!("Invalid PokerHand `{name}`")))
+}
+
+impl From<Round> for ScoringRound {
+    fn from(round: Round) -> Self {
+        Self {
+            cards_played: round
+                .cards_played
+                .into_iter()
+                .map(PlayingCard::from)
+                .collect(),
+            cards_held_in_hand: round
+                .cards_held_in_hand
+                .into_iter()
+                .map(PlayingCard::from)
+                .collect(),
+            ..Self::with_jokers(round.jokers.into_iter().map(JokerSlot::from).collect())
+        }
+    }
+}
+
+fn deserialize_hand_levels<'de, D>(deserializer: D) -> Result<HashMap<PokerHand, u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, u32>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, level)| {
+            let hand = parse_poker_hand(&name)
+                .ok_or_else(|| D::Error::custom(format!("Invalid PokerHand `{name}`")))?;
+            if level == 0 {
+                return Err(D::Error::custom(format!("{hand} level must be at least 1")));
+            }
+            Ok((hand, level))
+        })
+        .collect()
+}
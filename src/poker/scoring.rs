@@ -1,27 +1,62 @@
+use crate::error::OrtalabError;
+use crate::poker::breakdown::{CardReward, Reward, RewardCause, ScoreBreakdown};
+use crate::poker::cards::PlayingCard;
 use crate::poker::determine_poker_hand;
-use crate::poker::jokers::joker_application;
-use crate::poker::modifiers::compute_enhancement;
-use ortalib::{Chips, Joker, Mult, Round};
+use crate::poker::enhancements::{ExtraEnhancement, gold_rewards};
+use crate::poker::explain::Explain;
+use crate::poker::joker_effects::SPACE_JOKER;
+use crate::poker::jokers::{
+    JokerContext, JokerRegistry, apply_held_card_jokers, apply_scored_card_jokers,
+    joker_application,
+};
+use crate::poker::levels::leveled_hand_value;
+use crate::poker::luck::{ExpectedScore, Luck};
+use crate::poker::modifiers::{apply_extra_enhancement, compute_enhancement};
+use crate::poker::number::{Exact, Number};
+use crate::poker::resolution::resolve_jokers;
+use crate::poker::retriggers::{expand_held, expand_scored};
+use crate::poker::round::ScoringRound;
+use crate::poker::seals::seal_rewards;
+use itertools::Itertools;
+use ortalib::{Chips, Enhancement, Joker, Mult};
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 /// Computes the final chip score and multiplier for a given poker round.
 ///
 /// This function:
-/// - Determines the best poker hand from the cards played and jokers.
-/// - Adjusts the base score with rank values of the scored cards.
-/// - Applies enhancements from modifiers and held cards.
-/// - Applies joker effects to further modify the result.
+/// - Determines the best poker hand from the cards played and jokers, and
+///   its base value at the round's hand level (halved by The Flint).
+/// - Skips every card debuffed by the round's boss blind.
+/// - Scores each played card in order: its rank chips (or a Stone card's
+///   +50 Chips), enhancement and edition, then every on‑scored joker that
+///   triggers on it.
+/// - Triggers each card held in hand: Steel, then every on‑held joker.
+/// - Repeats a card's whole step for each retrigger (Sock and Buskin, Mime,
+///   Red seals).
+/// - Reports what Lucky cards, seals and Gold cards held in hand give at the
+///   end of the round.
+/// - Applies the remaining joker effects, left to right.
+/// - Rolls every chance (Bloodstone, Space Joker, Lucky cards, shattering
+///   Glass) with the default seed; see [`score_with_luck`] and
+///   [`score_expected`].
 ///
 /// # Arguments
-/// * `round` - A [`Round`] containing the cards played, jokers, and cards held in hand.
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
 ///
 /// # Returns
 /// A tuple `(Chips, Mult)` where:
 /// - `Chips` is the final chip value after all scoring rules.
 /// - `Mult` is the multiplier applied to the chip value.
 ///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if the chips or mult grow past
+/// `f64::MAX`.
+///
 /// # Example
 /// ```
-/// use ortalib::{Round, Chips, Mult, Suit, Rank, Card};
+/// use ortalib::{Round, Chips, Mult, Suit, Rank, Card, Enhancement, Joker, JokerCard};
 /// use ortalab::poker::scoring::score;
 ///
 /// // Construct a dummy round (details depend on your Round struct)
@@ -37,35 +72,576 @@ use ortalib::{Chips, Joker, Mult, Round};
 ///     jokers: vec![],
 /// };
 ///
-/// let (chips, mult): (Chips, Mult) = score(round);
+/// let (chips, mult): (Chips, Mult) = score(round).unwrap();
 /// assert!(chips >= 1.0);
 /// assert!(mult >= 1.0);
+///
+/// // Cards resolve one at a time: the Lusty Joker's +3 on K♥ lands
+/// // before the Glass K♦ doubles the multiplier
+/// let round = Round {
+///     cards_played: vec![
+///         Card::new(Rank::King, Suit::Hearts, None, None),
+///         Card::new(Rank::King, Suit::Diamonds, Some(Enhancement::Glass), None),
+///     ],
+///     cards_held_in_hand: vec![],
+///     jokers: vec![JokerCard::new(Joker::LustyJoker, None)],
+/// };
+/// assert_eq!(score(round).unwrap(), (30.0, 10.0)); // (2 + 3) x 2
+///
+/// // A round without played cards cannot be scored
+/// let empty = Round { cards_played: vec![], cards_held_in_hand: vec![], jokers: vec![] };
+/// assert!(score(empty).is_err());
+/// ```
+pub fn score(round: impl Into<ScoringRound>) -> Result<(Chips, Mult), OrtalabError> {
+    Ok(score_breakdown(round)?.after_jokers)
+}
+
+/// Scores a round and returns every stage of the computation.
+///
+/// See [`ScoreBreakdown`] for what is reported. The final
+/// [`after_jokers`](ScoreBreakdown::after_jokers) is identical to what
+/// [`score`] returns.
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+///
+/// # Returns
+/// The [`ScoreBreakdown`] of the round.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if the chips or mult grow past
+/// `f64::MAX`.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Enhancement, Joker, JokerCard, PokerHand, Rank, Round, Suit};
+/// use ortalab::poker::big_score::BigScore;
+/// use ortalab::poker::scoring::score_breakdown;
+///
+/// let king = Card::new(Rank::King, Suit::Hearts, None, None);
+/// let other_king = Card::new(Rank::King, Suit::Spades, None, None);
+/// let three = Card::new(Rank::Three, Suit::Clubs, None, None);
+/// let steel = Card::new(Rank::Two, Suit::Clubs, Some(Enhancement::Steel), None);
+///
+/// let breakdown = score_breakdown(Round {
+///     cards_played: vec![king, three, other_king],
+///     cards_held_in_hand: vec![steel],
+///     jokers: vec![JokerCard::new(Joker::Joker, None)],
+/// })
+/// .unwrap();
+///
+/// assert_eq!(breakdown.hand, PokerHand::Pair);
+/// assert_eq!(breakdown.scoring_cards, [king.into(), other_king.into()]);
+/// assert_eq!(breakdown.unscored_cards, [three.into()]);
+/// assert_eq!(breakdown.base, (10.0, 2.0));
+/// assert_eq!(breakdown.after_cards, (30.0, 2.0));
+/// assert_eq!(breakdown.after_held, (30.0, 3.0));
+/// assert_eq!(breakdown.after_jokers, (30.0, 7.0));
+/// assert_eq!(breakdown.score, BigScore::from(210));
 /// ```
-pub fn score(round: Round) -> (Chips, Mult) {
-    let mut result;
+pub fn score_breakdown(round: impl Into<ScoringRound>) -> Result<ScoreBreakdown, OrtalabError> {
+    score_with_registry(round, JokerRegistry::builtin(), &mut Explain::disabled())
+}
+
+/// Computes the final chip score and multiplier, recording every step.
+///
+/// This is the same pipeline as [`score`], but each step that changes the
+/// score (the detected hand, each scored card's rank chips, enhancements,
+/// editions, held Steel cards and every Joker that fires) is recorded in
+/// `explain` together with the running chips × mult.
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+/// * `explain` - The trace to record into.
+///
+/// # Returns
+/// A tuple `(Chips, Mult)`, identical to what [`score`] returns.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if the chips or mult grow past
+/// `f64::MAX`.
+///
+/// # Example
+/// ```
+/// use ortalib::{Round, Suit, Rank, Card};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::scoring::score_explained;
+///
+/// let round = Round {
+///     cards_played: vec![
+///         Card::new(Rank::King, Suit::Hearts, None, None),
+///         Card::new(Rank::King, Suit::Spades, None, None),
+///     ],
+///     cards_held_in_hand: vec![],
+///     jokers: vec![],
+/// };
+///
+/// let mut explain = Explain::new(true);
+/// let (chips, mult) = score_explained(round, &mut explain).unwrap();
+///
+/// assert_eq!((chips, mult), (30.0, 2.0));
+/// assert_eq!(explain.steps()[0].label, "Pair lvl.1: 10 Chips x 2 Mult");
+/// assert_eq!(explain.steps().len(), 3);
+///
+/// // The Head debuffs the K♥: it still makes the Pair, but gives no chips
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♥, K♠]\nblind: The Head").unwrap();
+/// let mut explain = Explain::new(true);
+/// assert_eq!(score_explained(round, &mut explain).unwrap(), (20.0, 2.0));
+/// assert_eq!(explain.steps()[1].label, "scored K♥ debuffed by The Head");
+/// ```
+pub fn score_explained(
+    round: impl Into<ScoringRound>,
+    explain: &mut Explain,
+) -> Result<(Chips, Mult), OrtalabError> {
+    Ok(score_with_registry(round, JokerRegistry::builtin(), explain)?.after_jokers)
+}
+
+/// Scores a round, dispatching every Joker through `registry`.
+///
+/// This is the same pipeline as [`score_explained`], but Joker abilities are
+/// looked up in `registry` instead of the built‑in one, so house‑rule Jokers
+/// (see [`JokerKind::Custom`](crate::poker::joker_slot::JokerKind::Custom))
+/// can take part in scoring. A custom Joker `registry` does not know is an
+/// error.
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+/// * `registry` - The Joker effects to dispatch through.
+/// * `explain` - The trace to record into.
+///
+/// # Returns
+/// The [`ScoreBreakdown`] of the round.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if the chips or mult grow past
+/// `f64::MAX`.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Joker, JokerCard, Rank, Round, Suit};
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::jokers::JokerRegistry;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::scoring::score_with_registry;
+///
+/// let round = ScoringRound::from(Round {
+///     cards_played: vec![Card::new(Rank::Ace, Suit::Hearts, None, None)],
+///     cards_held_in_hand: vec![],
+///     jokers: vec![JokerCard::new(Joker::Joker, None)],
+/// });
+///
+/// // Without a registered effect, the Joker does nothing
+/// let empty = JokerRegistry::new();
+/// let breakdown = score_with_registry(round.clone(), &empty, &mut Explain::disabled()).unwrap();
+/// assert_eq!(breakdown.after_jokers, (16.0, 1.0));
+///
+/// let standard = JokerRegistry::standard();
+/// let breakdown = score_with_registry(round, &standard, &mut Explain::disabled()).unwrap();
+/// assert_eq!(breakdown.after_jokers, (16.0, 5.0));
+/// ```
+pub fn score_with_registry(
+    round: impl Into<ScoringRound>,
+    registry: &JokerRegistry,
+    explain: &mut Explain,
+) -> Result<ScoreBreakdown, OrtalabError> {
+    score_in(round.into(), registry, &Luck::default(), explain)
+}
+
+/// Scores a round, rolling every chance with `luck`.
+///
+/// This is the same pipeline as [`score_with_registry`], which rolls with
+/// [`Luck::default`]. Give a [`Luck::seeded`] to get another reproducible
+/// outcome of **Bloodstone**, **Space Joker**, Lucky cards and Glass cards.
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+/// * `registry` - The Joker effects to dispatch through.
+/// * `luck` - Where every random outcome comes from.
+/// * `explain` - The trace to record into.
+///
+/// # Returns
+/// The [`ScoreBreakdown`] of the round.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if the chips or mult grow past
+/// `f64::MAX`.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::jokers::JokerRegistry;
+/// use ortalab::poker::luck::Luck;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::scoring::score_with_luck;
+///
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [A♥, K♥ Glass]\njokers: [Bloodstone, Space Joker]")
+///         .unwrap();
+/// let score = |seed| {
+///     let luck = Luck::seeded(seed);
+///     score_with_luck(round.clone(), JokerRegistry::builtin(), &luck, &mut Explain::disabled())
+///         .unwrap()
+/// };
+///
+/// // The same seed always gives the same outcome
+/// assert_eq!(score(42), score(42));
+/// ```
+pub fn score_with_luck(
+    round: impl Into<ScoringRound>,
+    registry: &JokerRegistry,
+    luck: &Luck,
+    explain: &mut Explain,
+) -> Result<ScoreBreakdown, OrtalabError> {
+    score_in(round.into(), registry, luck, explain)
+}
+
+/// How many times [`score_expected`] scores a round before giving up.
+pub const MAX_OUTCOMES: usize = 1 << 16;
+
+/// Scores a round under every outcome of its random effects.
+///
+/// Every combination of rolls that can change the score (see
+/// [`Luck::enumerating`]) is scored, together with its probability, so the
+/// expected score is exact rather than sampled. A Glass card that shatters
+/// or a Lucky card that wins $20 never does in these outcomes. Outcomes in
+/// the same state after a scored card are scored on from there only once,
+/// and outcomes with the same breakdown are merged (see
+/// [`ExpectedScore::enumerate`]).
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+///
+/// # Returns
+/// The [`ExpectedScore`] listing every distinct outcome.
+///
+/// # Errors
+/// Returns the error of scoring any outcome, and
+/// [`OrtalabError::TooManyOutcomes`] if the round is scored
+/// [`MAX_OUTCOMES`] times with outcomes left.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::big_score::BigScore;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::scoring::score_expected;
+///
+/// // 1 in 2 for the Heart to give x1.5: 32 x 2 or 32 x 3
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [A♥, A♠]\njokers: [Bloodstone]").unwrap();
+/// let expected = score_expected(round).unwrap();
+/// assert_eq!(expected.outcomes.len(), 2);
+/// assert_eq!(expected.mean(), 80.0); // (64 + 96) / 2
+///
+/// // Oops! All 6s makes 1 in 2 certain
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [A♥, A♠]\njokers: [Oops! All 6s, Bloodstone]")
+///         .unwrap();
+/// let expected = score_expected(round).unwrap();
+/// assert_eq!(expected.outcomes.len(), 1);
+/// assert_eq!(expected.outcomes[0].1.score, BigScore::from(96));
+///
+/// // Whether the Glass card shatters leaves the score as it is
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [A♥ Glass, K♥]\njokers: [Bloodstone]").unwrap();
+/// assert_eq!(score_expected(round).unwrap().outcomes.len(), 2);
+///
+/// // Sixteen Bloodstone rolls, but only 17 ways for them to land
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [J♥ Glass, Q♥ Glass, K♥ Glass, 9♥ Glass, 7♥ Glass]
+/// jokers: [Bloodstone, Sock And Buskin, Bloodstone]",
+/// )
+/// .unwrap();
+/// assert_eq!(score_expected(round).unwrap().outcomes.len(), 17);
+/// ```
+pub fn score_expected(round: impl Into<ScoringRound>) -> Result<ExpectedScore, OrtalabError> {
+    let round = round.into();
+    ExpectedScore::enumerate(MAX_OUTCOMES, |luck| {
+        score_in(
+            round.clone(),
+            JokerRegistry::builtin(),
+            luck,
+            &mut Explain::disabled(),
+        )
+    })
+}
+
+/// Scores a round with exact arithmetic, recording every step.
+///
+/// This is the same pipeline as [`score_explained`], but the chips and mult
+/// are kept [`Exact`], so no step ever rounds. The trace in `explain` still
+/// shows the nearest `f64` of each step.
+///
+/// # Arguments
+/// * `round` - A [`ScoringRound`] (or an [`ortalib::Round`]) containing the cards played,
+///   jokers, and cards held in hand.
+/// * `luck` - Where every random outcome comes from.
+/// * `explain` - The trace to record into.
+///
+/// # Returns
+/// The exact [`ScoreBreakdown`] of the round.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if no cards were played or a Joker
+/// is unknown, and [`OrtalabError::Overflow`] if an effect adds or multiplies
+/// by a negative or non‑finite amount, which [`Exact`] cannot hold.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::explain::Explain;
+/// use ortalab::poker::luck::Luck;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::scoring::{score_breakdown, score_exact};
+///
+/// // 33 Steel Kings held with Baron: 92 Chips x 1.5^66 Mult
+/// let held = vec!["K♠ Steel"; 33].join(", ");
+/// let round: ScoringRound = serde_yaml::from_str(&format!(
+///     "cards_played: [7♠ Bonus Foil]\ncards_held_in_hand: [{held}]\njokers: [Baron]"
+/// ))
+/// .unwrap();
+///
+/// let exact = score_exact(round.clone(), &Luck::default(), &mut Explain::disabled()).unwrap();
+/// let float = score_breakdown(round).unwrap();
+/// assert_eq!(exact.score.to_string(), "38531057186050");
+/// assert_eq!(float.score.to_string(), "38531057186051"); // one too many
+/// ```
+pub fn score_exact(
+    round: impl Into<ScoringRound>,
+    luck: &Luck,
+    explain: &mut Explain,
+) -> Result<ScoreBreakdown<Exact>, OrtalabError> {
+    score_in(round.into(), &JokerRegistry::standard(), luck, explain)
+}
+
+/// The scoring pipeline, in any [`Number`].
+fn score_in<N: Number>(
+    round: ScoringRound,
+    registry: &JokerRegistry<N>,
+    luck: &Luck,
+    explain: &mut Explain,
+) -> Result<ScoreBreakdown<N>, OrtalabError> {
+    if round.cards_played.is_empty() {
+        return Err(OrtalabError::InvalidRound("no cards played".to_string()));
+    }
+    if let Some(slot) = round
+        .jokers
+        .iter()
+        .find(|slot| !registry.is_known(&slot.joker))
+    {
+        return Err(OrtalabError::InvalidRound(format!(
+            "unknown Joker `{}`",
+            slot.joker
+        )));
+    }
+
     let (hand, return_card) = determine_poker_hand(&round.cards_played, &round.jokers);
-    result = hand.hand_value();
 
+    // Scored cards resolve in the order they were played
     let is_splash_joker_exists = round.jokers.iter().any(|card| card.joker == Joker::Splash);
-    let on_scored_cards = if !is_splash_joker_exists {
-        &return_card
-    } else {
-        &round.cards_played
-    };
-
-    result.0 = on_scored_cards
+    let on_scored_cards = round
+        .cards_played
         .iter()
-        .fold(result.0, |acc, x| acc + x.rank.rank_value());
-    result = compute_enhancement(on_scored_cards, result.0, result.1, false);
-    result = compute_enhancement(&round.cards_held_in_hand, result.0, result.1, true);
-    result = joker_application(
+        .filter(|&card| is_splash_joker_exists || return_card.contains(card))
+        .copied()
+        .collect_vec();
+    let unscored_cards = round
+        .cards_played
+        .iter()
+        .filter(|&card| !on_scored_cards.contains(card))
+        .copied()
+        .collect_vec();
+    let ctx = JokerContext::new(
+        hand,
         &round.jokers,
+        &on_scored_cards,
         &round.cards_held_in_hand,
-        on_scored_cards,
-        hand,
-        result.0,
-        result.1,
+    )
+    .with_round(&round.context)
+    .with_luck(luck);
+
+    let mut hand_level = round.hand_level(hand);
+    let (base_chips, base_mult) = leveled_hand_value(hand, hand_level);
+    explain.record(
+        || format!("{hand} lvl.{hand_level}: {base_chips} Chips x {base_mult} Mult"),
+        &(base_chips, base_mult),
     );
 
-    (result.0, result.1)
+    // Each Space Joker (or copy of one) may level up the hand before it scores
+    let space_jokers = resolve_jokers(&round.jokers)
+        .into_iter()
+        .filter(|joker| joker.as_ref().is_some_and(|joker| *joker == SPACE_JOKER))
+        .count();
+    for _ in 0..space_jokers {
+        if ctx.roll(1, 4) {
+            hand_level += 1;
+            let (chips, mult) = leveled_hand_value(hand, hand_level);
+            explain.record(
+                || format!("Space Joker: {hand} lvl.{hand_level}: {chips} Chips x {mult} Mult"),
+                &(chips, mult),
+            );
+        }
+    }
+    let (base_chips, base_mult) = leveled_hand_value(hand, hand_level);
+    let (base_chips, base_mult) = match &round.blind {
+        Some(blind) => {
+            let halved = blind.apply_to_base((base_chips, base_mult));
+            if halved != (base_chips, base_mult) {
+                explain.record(
+                    || format!("{blind}: {} Chips x {} Mult", halved.0, halved.1),
+                    &halved,
+                );
+            }
+            halved
+        }
+        None => (base_chips, base_mult),
+    };
+    let mut result = (N::from_f64(base_chips), N::from_f64(base_mult));
+    let base = result.clone();
+
+    // Outcomes that reach a checkpoint in the same state score the same from
+    // there on, so score_expected only scores on from it once
+    let checkpoint = |(chips, mult): &(N, N)| {
+        let mut state = DefaultHasher::new();
+        hand_level.hash(&mut state);
+        chips.hash_value(&mut state);
+        mult.hash_value(&mut state);
+        luck.checkpoint(state.finish());
+    };
+    checkpoint(&result);
+
+    let debuffed_by = |card: &PlayingCard| {
+        round
+            .blind
+            .as_ref()
+            .filter(|blind| blind.is_debuffed(&ctx, card))
+    };
+
+    // Each trigger of a scored card resolves fully before the next one:
+    // rank chips, enhancement and edition, then every on-scored Joker
+    let mut scored_triggers = Vec::new();
+    let mut rewards = Vec::new();
+    for card in &expand_scored(&on_scored_cards, &round.jokers) {
+        // A debuffed card gives nothing, and no Joker triggers on it
+        if let Some(blind) = debuffed_by(card) {
+            explain.record(
+                || format!("scored {}{} debuffed by {blind}", card.rank, card.suit),
+                &result,
+            );
+            continue;
+        }
+
+        scored_triggers.push(*card);
+        if !card.is_stone() {
+            result.0 += card.rank.rank_value();
+            explain.record(
+                || {
+                    format!(
+                        "scored {}{} +{} Chips",
+                        card.rank,
+                        card.suit,
+                        card.rank.rank_value()
+                    )
+                },
+                &result,
+            );
+        }
+        if let Some(enhancement) = card.extra_enhancement {
+            let (chip, mul) = result.clone();
+            let enhanced = apply_extra_enhancement(enhancement, chip, mul, &ctx);
+            if enhanced != result {
+                explain.record(
+                    || format!("scored {}{} {enhancement}", card.rank, card.suit),
+                    &enhanced,
+                );
+            }
+            result = enhanced;
+
+            if enhancement == ExtraEnhancement::Lucky && ctx.roll_unscored(1, 15) {
+                rewards.push(CardReward {
+                    card: *card,
+                    cause: RewardCause::Enhancement(enhancement),
+                    reward: Reward::Money(20),
+                });
+            }
+        }
+        let (chip, mul) = result;
+        let (chip, mul) =
+            compute_enhancement(std::slice::from_ref(card), chip, mul, false, explain);
+        result = apply_scored_card_jokers(registry, &ctx, card, chip, mul, explain);
+        checkpoint(&result);
+    }
+
+    let after_cards = result.clone();
+
+    // Each trigger of a held card: Steel, then every on-held Joker
+    for card in &expand_held(&round.cards_held_in_hand, &round.jokers) {
+        if let Some(blind) = debuffed_by(card) {
+            explain.record(
+                || format!("held {}{} debuffed by {blind}", card.rank, card.suit),
+                &result,
+            );
+            continue;
+        }
+
+        let (chip, mul) = result;
+        let (chip, mul) = compute_enhancement(std::slice::from_ref(card), chip, mul, true, explain);
+        result = apply_held_card_jokers(registry, &ctx, card, chip, mul, explain);
+    }
+    let after_held = result.clone();
+
+    let (chip, mul) = result;
+    let result = joker_application(registry, &ctx, chip, mul, explain);
+
+    // Scored Glass cards may shatter once scoring is finished
+    let mut destroyed_cards = Vec::new();
+    for card in &on_scored_cards {
+        let is_glass = card.enhancement == Some(Enhancement::Glass);
+        if is_glass && debuffed_by(card).is_none() && ctx.roll_unscored(1, 4) {
+            explain.record(
+                || format!("Glass {}{} shattered", card.rank, card.suit),
+                &result,
+            );
+            destroyed_cards.push(*card);
+        }
+    }
+
+    // Lucky cards, seals and held Gold cards pay out once the round is over,
+    // and leave the score unchanged
+    let held_cards = round
+        .cards_held_in_hand
+        .iter()
+        .filter(|&card| debuffed_by(card).is_none())
+        .copied()
+        .collect_vec();
+    rewards.extend(seal_rewards(hand, &scored_triggers, &held_cards));
+    rewards.extend(gold_rewards(&held_cards));
+    for reward in &rewards {
+        explain.record(|| reward.to_string(), &result);
+    }
+
+    let score = N::floor_score(result.0.clone(), result.1.clone()).ok_or(OrtalabError::Overflow)?;
+
+    Ok(ScoreBreakdown {
+        hand,
+        hand_level,
+        scoring_cards: on_scored_cards,
+        unscored_cards,
+        base,
+        after_cards,
+        after_held,
+        after_jokers: result,
+        score,
+        destroyed_cards,
+        rewards,
+    })
 }
@@ -0,0 +1,119 @@
+//! # Seals
+//!
+//! [`ortalib::Card`] has no seal. Ortalab reads one as the last word of a
+//! card (e.g. `K♠ Steel Red` or `A♥ Blue`, see
+//! [`parse_card`](crate::poker::cards::parse_card)) into the card's
+//! [`PlayingCard::seal`], which goes wherever the card goes: played, held in
+//! hand or dealt from a deck.
+//!
+//! - **Red**: the card triggers one more time, both when scored and when
+//!   held in hand.
+//! - **Gold**: **$3** each time the card scores.
+//! - **Blue**: held in hand at the end of the round, creates the Planet card
+//!   of the played poker hand.
+//! - **Purple**: creates a Tarot card when discarded. Scoring a round
+//!   discards nothing, so it never triggers here.
+//!
+//! Gold and Blue seals do not change the score; what they give is reported
+//! as [`CardReward`]s in the [`ScoreBreakdown`](crate::poker::breakdown::ScoreBreakdown).
+
+use crate::poker::{
+    breakdown::{CardReward, Reward, RewardCause},
+    cards::PlayingCard,
+};
+use ortalib::PokerHand;
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A seal on a playing card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Seal {
+    Red,
+    Gold,
+    Blue,
+    Purple,
+}
+
+impl Display for Seal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Seal::Red => "Red",
+            Seal::Gold => "Gold",
+            Seal::Blue => "Blue",
+            Seal::Purple => "Purple",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Seal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Red" => Ok(Seal::Red),
+            "Gold" => Ok(Seal::Gold),
+            "Blue" => Ok(Seal::Blue),
+            "Purple" => Ok(Seal::Purple),
+            _ => Err(format!("Invalid Seal `{s}`")),
+        }
+    }
+}
+
+/// The end‑of‑round rewards of the sealed cards in a round.
+///
+/// # Arguments
+/// * `hand` - The played poker hand.
+/// * `scored` - Every scored trigger (one entry per trigger, see
+///   [`expand_scored`](crate::poker::retriggers::expand_scored)).
+/// * `held` - The cards left in hand.
+///
+/// # Returns
+/// The rewards, scored cards first.
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::cards::parse_card;
+/// use ortalab::poker::seals::seal_rewards;
+///
+/// let gold = parse_card("K♥ Gold Seal").unwrap();
+/// // A Purple seal only triggers when discarded
+/// let held = [parse_card("2♠ Blue").unwrap(), parse_card("3♠ Purple").unwrap()];
+///
+/// let rewards = seal_rewards(PokerHand::Pair, &[gold, gold], &held);
+/// let shown = rewards.iter().map(ToString::to_string).collect::<Vec<_>>();
+/// assert_eq!(
+///     shown,
+///     [
+///         "Gold seal K♥: +$3",
+///         "Gold seal K♥: +$3",
+///         "Blue seal 2♠: Mercury (Pair)",
+///     ]
+/// );
+/// ```
+pub fn seal_rewards(
+    hand: PokerHand,
+    scored: &[PlayingCard],
+    held: &[PlayingCard],
+) -> Vec<CardReward> {
+    let scored = scored.iter().filter_map(|&card| match card.seal? {
+        Seal::Gold => Some((card, Seal::Gold, Reward::Money(3))),
+        _ => None,
+    });
+    let held = held.iter().filter_map(|&card| match card.seal? {
+        Seal::Blue => Some((card, Seal::Blue, Reward::Planet(hand))),
+        _ => None,
+    });
+
+    scored
+        .chain(held)
+        .map(|(card, seal, reward)| CardReward {
+            card,
+            cause: RewardCause::Seal(seal),
+            reward,
+        })
+        .collect()
+}
@@ -0,0 +1,247 @@
+//! # Monte Carlo Simulation
+//!
+//! [`simulate`] estimates how a Joker build scores in real play: it deals
+//! many hands from a shuffled [`Deck`], plays each one with a [`Strategy`],
+//! and collects the scores into a [`SimulationReport`] (mean, median,
+//! percentiles and a histogram).
+//!
+//! Every shuffle draws from one [`SeededRng`], and so does the seed each hand
+//! rolls its chances (Bloodstone, Lucky cards, ...) with, so the same
+//! configuration and seed always produce the same report, down to the byte.
+
+use crate::{
+    error::OrtalabError,
+    poker::{
+        deck::Deck, explain::Explain, joker_slot::JokerSlot, jokers::JokerRegistry, luck::Luck,
+        rng::SeededRng, round::ScoringRound, scoring::score_with_luck, solver::solve_with_seed,
+        validation::MAX_CARDS_PLAYED,
+    },
+};
+use std::fmt::{self, Display, Formatter};
+
+/// How a dealt hand is played.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// The highest scoring play, found by the [solver](crate::poker::solver).
+    Best,
+    /// The first five cards drawn, holding the rest.
+    FirstFive,
+}
+
+/// What to simulate.
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    /// The deck every hand is dealt from (reshuffled for each hand).
+    pub deck: Deck,
+    /// The Jokers in play, left to right.
+    pub jokers: Vec<JokerSlot>,
+    /// How many cards are dealt into each hand.
+    pub hand_size: usize,
+    /// How many hands to deal.
+    pub hands: usize,
+    /// The seed of the shuffles and rolls; the same seed deals and scores
+    /// the same hands.
+    pub seed: u64,
+    /// How each dealt hand is played.
+    pub strategy: Strategy,
+}
+
+/// Deals and scores every hand of `config`.
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if the deck cannot deal a single
+/// card, or the hand size exceeds what the solver can search.
+///
+/// # Example
+/// ```
+/// use ortalib::Joker;
+/// use ortalab::poker::deck::Deck;
+/// use ortalab::poker::joker_slot::JokerSlot;
+/// use ortalab::poker::simulation::{SimulationConfig, Strategy, simulate};
+///
+/// let config = SimulationConfig {
+///     deck: Deck::standard(),
+///     jokers: vec![JokerSlot::new(Joker::Joker, None)],
+///     hand_size: 8,
+///     hands: 20,
+///     seed: 1,
+///     strategy: Strategy::Best,
+/// };
+///
+/// let report = simulate(&config).unwrap();
+/// assert_eq!(report.scores().len(), 20);
+/// assert!(report.percentile(10.0) <= report.median());
+///
+/// // The same seed gives the same report
+/// assert_eq!(report.to_string(), simulate(&config).unwrap().to_string());
+///
+/// // Every hand rolls its own chances: ten K♥ always deal the same hand,
+/// // but Bloodstone does not always trigger on it
+/// let config = SimulationConfig {
+///     deck: Deck::from_yaml("cards: [K♥, K♥, K♥, K♥, K♥, K♥, K♥, K♥, K♥, K♥]").unwrap(),
+///     jokers: vec!["Bloodstone".parse().unwrap()],
+///     hands: 50,
+///     strategy: Strategy::FirstFive,
+///     ..config
+/// };
+/// let report = simulate(&config).unwrap();
+/// assert!(report.percentile(10.0) < report.percentile(90.0));
+/// ```
+pub fn simulate(config: &SimulationConfig) -> Result<SimulationReport, OrtalabError> {
+    let mut rng = SeededRng::new(config.seed);
+    let mut scores = Vec::with_capacity(config.hands);
+    let registry = JokerRegistry::builtin();
+
+    for _ in 0..config.hands {
+        let mut deck = config.deck.clone();
+        deck.shuffle(&mut rng);
+        deck.draw_to(config.hand_size);
+        let seed = rng.next_u64();
+
+        let hand = deck.hand();
+        let played = hand.len().min(MAX_CARDS_PLAYED);
+        let round = ScoringRound {
+            cards_played: hand[..played].to_vec(),
+            cards_held_in_hand: hand[played..].to_vec(),
+            ..ScoringRound::with_jokers(config.jokers.clone())
+        };
+
+        let score = match config.strategy {
+            Strategy::Best => solve_with_seed(&round, registry, seed)?.breakdown.score,
+            Strategy::FirstFive => {
+                let luck = Luck::seeded(seed);
+                score_with_luck(round, registry, &luck, &mut Explain::disabled())?.score
+            }
+        };
+        scores.push(score.to_f64());
+    }
+
+    Ok(SimulationReport::new(scores))
+}
+
+/// The scores of a simulation, and statistics over them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationReport {
+    /// Every score, lowest first.
+    scores: Vec<f64>,
+}
+
+/// How many bars the histogram is split into.
+const HISTOGRAM_BINS: usize = 10;
+
+/// The width of the longest histogram bar.
+const HISTOGRAM_WIDTH: usize = 40;
+
+impl SimulationReport {
+    pub fn new(mut scores: Vec<f64>) -> Self {
+        scores.sort_by(f64::total_cmp);
+        Self { scores }
+    }
+
+    /// Every score, lowest first.
+    pub fn scores(&self) -> &[f64] {
+        &self.scores
+    }
+
+    /// The mean score (0 without scores).
+    pub fn mean(&self) -> f64 {
+        if self.scores.is_empty() {
+            return 0.0;
+        }
+        self.scores.iter().sum::<f64>() / self.scores.len() as f64
+    }
+
+    /// The median score (the 50th percentile).
+    pub fn median(&self) -> f64 {
+        self.percentile(50.0)
+    }
+
+    /// The score below which `percent`% of the scores fall (nearest rank;
+    /// 0 without scores).
+    ///
+    /// # Example
+    /// ```
+    /// use ortalab::poker::simulation::SimulationReport;
+    ///
+    /// let report = SimulationReport::new(vec![40.0, 10.0, 30.0, 20.0]);
+    /// assert_eq!(report.percentile(25.0), 10.0);
+    /// assert_eq!(report.median(), 20.0);
+    /// assert_eq!(report.percentile(100.0), 40.0);
+    /// assert_eq!(report.mean(), 25.0);
+    /// ```
+    pub fn percentile(&self, percent: f64) -> f64 {
+        if self.scores.is_empty() {
+            return 0.0;
+        }
+        let rank = (percent.clamp(0.0, 100.0) / 100.0 * self.scores.len() as f64).ceil();
+        self.scores[(rank as usize).saturating_sub(1)]
+    }
+
+    /// The scores split into equal‑width bins between the lowest and highest
+    /// score, as `(from, to, count)`.
+    ///
+    /// # Example
+    /// ```
+    /// use ortalab::poker::simulation::SimulationReport;
+    ///
+    /// let report = SimulationReport::new(vec![0.0, 5.0, 10.0]);
+    /// let histogram = report.histogram(2);
+    /// assert_eq!(histogram, vec![(0.0, 5.0, 1), (5.0, 10.0, 2)]);
+    /// ```
+    pub fn histogram(&self, bins: usize) -> Vec<(f64, f64, usize)> {
+        let (Some(&low), Some(&high)) = (self.scores.first(), self.scores.last()) else {
+            return Vec::new();
+        };
+        let bins = if low == high { 1 } else { bins.max(1) };
+        let width = (high - low) / bins as f64;
+
+        let mut counts = vec![0; bins];
+        for score in &self.scores {
+            let bin = if width == 0.0 {
+                0
+            } else {
+                (((score - low) / width) as usize).min(bins - 1)
+            };
+            counts[bin] += 1;
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(bin, count)| {
+                let from = low + width * bin as f64;
+                let to = if bin + 1 == bins {
+                    high
+                } else {
+                    low + width * (bin + 1) as f64
+                };
+                (from, to, count)
+            })
+            .collect()
+    }
+}
+
+impl Display for SimulationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "hands   {}", self.scores.len())?;
+        writeln!(f, "mean    {:.2}", self.mean())?;
+        writeln!(f, "median  {}", self.median())?;
+        for percent in [10.0, 25.0, 75.0, 90.0, 99.0] {
+            writeln!(f, "p{percent:<6} {}", self.percentile(percent))?;
+        }
+
+        let histogram = self.histogram(HISTOGRAM_BINS);
+        let most = histogram
+            .iter()
+            .map(|&(_, _, count)| count)
+            .max()
+            .unwrap_or_default();
+
+        writeln!(f)?;
+        for (from, to, count) in histogram {
+            let bar = "#".repeat((count * HISTOGRAM_WIDTH).div_ceil(most.max(1)));
+            writeln!(f, "{from:>12.0} - {to:<12.0} {count:>6} {bar}")?;
+        }
+        Ok(())
+    }
+}
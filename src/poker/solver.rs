@@ -0,0 +1,225 @@
+//! # Best‑Play Solver
+//!
+//! Given a player's whole hand, [`solve`] finds which cards to play, and in
+//! which order. It tries every legal play of 1 to 5 cards and scores it with
+//! the full pipeline; the cards not played stay in hand, so held‑card effects
+//! (Steel, Baron, Raised Fist, Blackboard, Mime) count towards each
+//! candidate.
+//!
+//! Scored cards resolve in the order they are played, so each play is tried
+//! in every distinct order of its scoring cards (+Mult before ×Mult). Cards
+//! that do not score never trigger, so they keep their place.
+
+use crate::{
+    error::OrtalabError,
+    poker::{
+        arrangement::distinct_orders,
+        breakdown::ScoreBreakdown,
+        cards::PlayingCard,
+        determine_poker_hand,
+        explain::Explain,
+        jokers::JokerRegistry,
+        luck::{DEFAULT_SEED, Luck},
+        round::ScoringRound,
+        scoring::score_with_luck,
+        validation::MAX_CARDS_PLAYED,
+    },
+};
+use itertools::{Either, Itertools};
+use ortalib::Joker;
+
+/// The most cards a player can hold before choosing a play.
+pub const MAX_HAND_SIZE: usize = 8;
+
+/// The best play found by [`solve`].
+#[derive(Clone, Debug)]
+pub struct Solution {
+    /// The round as it should be played: the chosen `cards_played`, and the
+    /// rest of the hand as `cards_held_in_hand`.
+    pub round: ScoringRound,
+    /// How that round scores.
+    pub breakdown: ScoreBreakdown,
+}
+
+/// Finds the highest scoring play from a whole hand.
+///
+/// The hand is every card of `round`, played and held alike, in that order.
+/// Each subset of 1 to 5 cards is played in every distinct order of its
+/// scoring cards with the rest held, and scored with the built‑in Jokers at
+/// the round's hand levels. On a tie, the play with the fewest cards (then
+/// the earliest in hand order) wins.
+///
+/// # Arguments
+/// * `round` — The hand to solve, with its Jokers.
+///
+/// # Returns
+/// The best [`Solution`].
+///
+/// # Errors
+/// Returns [`OrtalabError::InvalidRound`] if the hand is empty or holds more
+/// than [`MAX_HAND_SIZE`] cards.
+///
+/// # Example
+/// ```
+/// use ortalib::PokerHand;
+/// use ortalab::poker::big_score::BigScore;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::solver::solve;
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [Q♠, Q♥, 2♣, K♦ Steel]\njokers: [Baron, Mime]",
+/// )
+/// .unwrap();
+///
+/// // Keeping the Steel King in hand beats playing it:
+/// // (10 + 20) x 2 x (1.5 x 1.5) x (1.5 x 1.5), with Mime retriggering it
+/// let solution = solve(&round).unwrap();
+/// assert_eq!(solution.breakdown.hand, PokerHand::Pair);
+/// assert_eq!(solution.round.cards_played.len(), 2);
+/// assert_eq!(solution.round.cards_held_in_hand.len(), 2);
+/// assert_eq!(solution.breakdown.score, BigScore::from(303));
+///
+/// // A levelled High Card outscores a level 1 Pair
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [Q♠, Q♥, 2♣]\nhand_levels: {High Card: 10}",
+/// )
+/// .unwrap();
+/// let solution = solve(&round).unwrap();
+/// assert_eq!(solution.breakdown.hand, PokerHand::HighCard);
+/// assert_eq!(solution.breakdown.score, BigScore::from(1050)); // (95 + 10) x 10
+///
+/// // The Mult King scores before the Glass King doubles it
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♥ Glass, K♠ Mult]").unwrap();
+/// let solution = solve(&round).unwrap();
+/// assert_eq!(solution.round.cards_played[0].to_string(), "K♠ Mult");
+/// assert_eq!(solution.breakdown.score, BigScore::from(360)); // (10 + 20) x (2 + 4) x 2
+/// ```
+pub fn solve(round: &ScoringRound) -> Result<Solution, OrtalabError> {
+    solve_with_registry(round, JokerRegistry::builtin())
+}
+
+/// Same as [`solve`], dispatching every Joker through `registry`.
+pub fn solve_with_registry(
+    round: &ScoringRound,
+    registry: &JokerRegistry,
+) -> Result<Solution, OrtalabError> {
+    solve_with_seed(round, registry, DEFAULT_SEED)
+}
+
+/// Same as [`solve_with_registry`], rolling every chance with `seed`.
+///
+/// Each candidate play is scored with its own [`Luck::seeded`]`(seed)`, so
+/// every candidate faces the same rolls and none wins by luck alone.
+///
+/// # Example
+/// ```
+/// use ortalab::poker::jokers::JokerRegistry;
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::solver::solve_with_seed;
+///
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♥, K♦, 7♥]\njokers: [Bloodstone]").unwrap();
+/// let scores: Vec<_> = (0..16)
+///     .map(|seed| solve_with_seed(&round, JokerRegistry::builtin(), seed).unwrap())
+///     .map(|solution| solution.breakdown.score)
+///     .collect();
+///
+/// // Bloodstone triggers on some seeds and not on others
+/// assert!(scores.iter().any(|score| *score != scores[0]));
+/// ```
+pub fn solve_with_seed(
+    round: &ScoringRound,
+    registry: &JokerRegistry,
+    seed: u64,
+) -> Result<Solution, OrtalabError> {
+    let hand = round
+        .cards_played
+        .iter()
+        .chain(&round.cards_held_in_hand)
+        .copied()
+        .collect_vec();
+
+    if hand.is_empty() {
+        return Err(OrtalabError::InvalidRound("no cards in hand".to_string()));
+    }
+    if hand.len() > MAX_HAND_SIZE {
+        return Err(OrtalabError::InvalidRound(format!(
+            "{} cards in hand (at most {MAX_HAND_SIZE})",
+            hand.len()
+        )));
+    }
+
+    let mut best: Option<Solution> = None;
+    for size in 1..=hand.len().min(MAX_CARDS_PLAYED) {
+        for played in (0..hand.len()).combinations(size) {
+            for candidate in play_orders(&play(round, &hand, &played)) {
+                let luck = Luck::seeded(seed);
+                let breakdown =
+                    score_with_luck(candidate.clone(), registry, &luck, &mut Explain::disabled())?;
+
+                if best
+                    .as_ref()
+                    .is_none_or(|best| breakdown.score > best.breakdown.score)
+                {
+                    best = Some(Solution {
+                        round: candidate,
+                        breakdown,
+                    });
+                }
+            }
+        }
+    }
+
+    // The hand is not empty, so at least one play was scored
+    best.ok_or_else(|| OrtalabError::InvalidRound("no cards in hand".to_string()))
+}
+
+/// `round` with the cards of `hand` at the `played` positions played and the
+/// rest held, both in hand order.
+fn play(round: &ScoringRound, hand: &[PlayingCard], played: &[usize]) -> ScoringRound {
+    let (cards_played, cards_held_in_hand): (Vec<_>, Vec<_>) =
+        hand.iter().enumerate().partition_map(|(position, card)| {
+            if played.contains(&position) {
+                Either::Left(*card)
+            } else {
+                Either::Right(*card)
+            }
+        });
+
+    ScoringRound {
+        cards_played,
+        cards_held_in_hand,
+        ..round.clone()
+    }
+}
+
+/// `round` played in every distinct order of its scoring cards, starting
+/// with the current one. The cards that do not score keep their place.
+fn play_orders(round: &ScoringRound) -> Vec<ScoringRound> {
+    let (_, scoring_cards) = determine_poker_hand(&round.cards_played, &round.jokers);
+    let is_splash_exists = round.jokers.iter().any(|slot| slot.joker == Joker::Splash);
+    let positions = (0..round.cards_played.len())
+        .filter(|&position| {
+            is_splash_exists || scoring_cards.contains(&round.cards_played[position])
+        })
+        .collect_vec();
+    let scored = positions
+        .iter()
+        .map(|&position| round.cards_played[position])
+        .collect_vec();
+
+    distinct_orders(&scored)
+        .into_iter()
+        .map(|order| {
+            let mut cards_played = round.cards_played.clone();
+            for (&position, card) in positions.iter().zip(order) {
+                cards_played[position] = card;
+            }
+            ScoringRound {
+                cards_played,
+                ..round.clone()
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,244 @@
+//! # Round Validation
+//!
+//! [`validate`] checks that a [`ScoringRound`] could actually happen in a
+//! game before it is trusted, and reports **every** rule it breaks rather
+//! than stopping at the first one:
+//!
+//! - more than [`MAX_CARDS_PLAYED`] cards played, or none at all;
+//! - more Jokers than the round's Joker slots;
+//! - an enhancement placed where it can never take effect (e.g. a Steel card
+//!   that was played, a Glass card held in hand, or a Bonus card played but
+//!   left out of the scoring hand), including Ortalab's extra enhancements
+//!   (e.g. a Stone card held in hand or a Gold card that was played).
+
+use crate::poker::{
+    cards::PlayingCard, determine_poker_hand, enhancements::ExtraEnhancement,
+    joker_slot::JokerKind, resolution::resolve_jokers, round::ScoringRound,
+};
+use ortalib::{Enhancement, Joker};
+use std::fmt::{self, Display, Formatter};
+
+/// The most cards a single hand may play.
+pub const MAX_CARDS_PLAYED: usize = 5;
+
+/// One rule broken by a round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Violation {
+    /// No cards were played.
+    NoCardsPlayed,
+
+    /// More than [`MAX_CARDS_PLAYED`] cards were played.
+    TooManyCardsPlayed { count: usize },
+
+    /// More Jokers than there are Joker slots.
+    TooManyJokers { count: usize, slots: usize },
+
+    /// An enhanced card that can never trigger where it was placed.
+    IneffectiveEnhancement {
+        card: PlayingCard,
+        enhancement: Enhancement,
+        in_hand: bool,
+    },
+
+    /// A card with an [`ExtraEnhancement`] that can never trigger where it
+    /// was placed.
+    IneffectiveExtraEnhancement {
+        card: PlayingCard,
+        enhancement: ExtraEnhancement,
+        in_hand: bool,
+    },
+
+    /// A played card whose enhancement only triggers when scored, but that
+    /// is not part of the scoring hand.
+    UnscoredEnhancement {
+        card: PlayingCard,
+        enhancement: Enhancement,
+    },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::NoCardsPlayed => write!(f, "no cards played"),
+            Violation::TooManyCardsPlayed { count } => {
+                write!(f, "{count} cards played (at most {MAX_CARDS_PLAYED})")
+            }
+            Violation::TooManyJokers { count, slots } => {
+                write!(f, "{count} Jokers in play but only {slots} Joker slots")
+            }
+            Violation::IneffectiveEnhancement {
+                card,
+                enhancement,
+                in_hand,
+            } => {
+                let location = if *in_hand { "held in hand" } else { "played" };
+                write!(
+                    f,
+                    "{}{} is {location}, where {enhancement} has no effect",
+                    card.rank, card.suit
+                )
+            }
+            Violation::IneffectiveExtraEnhancement {
+                card,
+                enhancement,
+                in_hand,
+            } => {
+                let location = if *in_hand { "held in hand" } else { "played" };
+                write!(
+                    f,
+                    "{}{} is {location}, where {enhancement} has no effect",
+                    card.rank, card.suit
+                )
+            }
+            Violation::UnscoredEnhancement { card, enhancement } => write!(
+                f,
+                "{}{} is played but does not score, so {enhancement} has no effect",
+                card.rank, card.suit
+            ),
+        }
+    }
+}
+
+/// Checks a round against the rules of the game.
+///
+/// An enhancement is ineffective when:
+/// - a **Steel** card is played (Steel only triggers when held);
+/// - a **Bonus**, **Mult** or **Glass** card is held (they only trigger when scored);
+/// - a **Wild** card is held and no Blackboard (or copy of one) could look at it;
+/// - a **Stone** or **Lucky** card is held (they only trigger when scored);
+/// - a **Gold** card is played (Gold only pays out when held).
+///
+/// A played **Bonus**, **Mult** or **Glass** card that is not part of the
+/// scoring hand is reported too, unless Splash makes every played card score.
+///
+/// # Arguments
+/// * `round` — The round to check.
+///
+/// # Returns
+/// Every [`Violation`] found, in the order above; empty if the round is legal.
+///
+/// # Example
+/// ```
+/// use ortalib::{Card, Enhancement, Rank, Suit};
+/// use ortalab::poker::round::ScoringRound;
+/// use ortalab::poker::validation::{Violation, validate};
+///
+/// let round: ScoringRound = serde_yaml::from_str(
+///     "cards_played: [A♠ Steel]\ncards_held_in_hand: [K♥]\njokers: [Joker, Joker]\njoker_slots: 1",
+/// )
+/// .unwrap();
+///
+/// let violations = validate(&round);
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0], Violation::TooManyJokers { count: 2, slots: 1 });
+/// assert_eq!(violations[1].to_string(), "A♠ is played, where Steel has no effect");
+///
+/// // The 3♣ is not part of the Pair, so its Mult never triggers
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♠, K♥, 3♣ Mult]\ncards_held_in_hand: []\njokers: []")
+///         .unwrap();
+/// assert_eq!(
+///     validate(&round)[0].to_string(),
+///     "3♣ is played but does not score, so Mult has no effect"
+/// );
+///
+/// let round: ScoringRound =
+///     serde_yaml::from_str("cards_played: [K♠ Gold]\ncards_held_in_hand: [Q♥ Lucky]\njokers: []")
+///         .unwrap();
+/// let violations: Vec<String> = validate(&round).iter().map(ToString::to_string).collect();
+/// assert_eq!(
+///     violations,
+///     [
+///         "K♠ is played, where Gold has no effect",
+///         "Q♥ is held in hand, where Lucky has no effect",
+///     ]
+/// );
+///
+/// let empty: ScoringRound = serde_yaml::from_str("cards_played: []").unwrap();
+/// assert_eq!(validate(&empty), vec![Violation::NoCardsPlayed]);
+/// ```
+pub fn validate(round: &ScoringRound) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    let played = round.cards_played.len();
+    if played == 0 {
+        violations.push(Violation::NoCardsPlayed);
+    } else if played > MAX_CARDS_PLAYED {
+        violations.push(Violation::TooManyCardsPlayed { count: played });
+    }
+
+    if round.jokers.len() > round.joker_slots {
+        violations.push(Violation::TooManyJokers {
+            count: round.jokers.len(),
+            slots: round.joker_slots,
+        });
+    }
+
+    let is_blackboard_exists =
+        resolve_jokers(&round.jokers).contains(&Some(JokerKind::Standard(Joker::Blackboard)));
+
+    let is_splash_exists = round.jokers.iter().any(|slot| slot.joker == Joker::Splash);
+    let (_, scoring_cards) = determine_poker_hand(&round.cards_played, &round.jokers);
+
+    let played = round.cards_played.iter().map(|card| (card, false));
+    let held = round.cards_held_in_hand.iter().map(|card| (card, true));
+    played.chain(held).for_each(|(card, in_hand)| {
+        if let Some(enhancement) = card.extra_enhancement
+            && !is_extra_enhancement_effective(enhancement, in_hand)
+        {
+            violations.push(Violation::IneffectiveExtraEnhancement {
+                card: *card,
+                enhancement,
+                in_hand,
+            });
+        }
+
+        let Some(enhancement) = card.enhancement else {
+            return;
+        };
+        if !is_enhancement_effective(enhancement, in_hand, is_blackboard_exists) {
+            violations.push(Violation::IneffectiveEnhancement {
+                card: *card,
+                enhancement,
+                in_hand,
+            });
+        } else if !in_hand
+            && !is_splash_exists
+            && !scoring_cards.contains(card)
+            && matches!(
+                enhancement,
+                Enhancement::Bonus | Enhancement::Mult | Enhancement::Glass
+            )
+        {
+            violations.push(Violation::UnscoredEnhancement {
+                card: *card,
+                enhancement,
+            });
+        }
+    });
+
+    violations
+}
+
+/// Whether `enhancement` can ever trigger on a card played (`in_hand` false)
+/// or held in hand (`in_hand` true).
+fn is_enhancement_effective(
+    enhancement: Enhancement,
+    in_hand: bool,
+    is_blackboard_exists: bool,
+) -> bool {
+    match enhancement {
+        Enhancement::Bonus | Enhancement::Mult | Enhancement::Glass => !in_hand,
+        Enhancement::Steel => in_hand,
+        Enhancement::Wild => !in_hand || is_blackboard_exists,
+    }
+}
+
+/// Whether an [`ExtraEnhancement`] can ever trigger on a card played
+/// (`in_hand` false) or held in hand (`in_hand` true).
+fn is_extra_enhancement_effective(enhancement: ExtraEnhancement, in_hand: bool) -> bool {
+    match enhancement {
+        ExtraEnhancement::Stone | ExtraEnhancement::Lucky => !in_hand,
+        ExtraEnhancement::Gold => in_hand,
+    }
+}
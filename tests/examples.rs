@@ -0,0 +1,33 @@
+//! Scores every example round and checks it against the `expected_score`
+//! and `expected_hand` recorded in the file.
+
+use ortalab::{
+    golden::{GoldenOutcome, check_files},
+    io::collect_round_files,
+};
+use std::path::PathBuf;
+
+fn check_examples(dir: &str) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(dir);
+    let files = collect_round_files(&[dir]);
+    assert!(!files.is_empty(), "no round files found");
+
+    let report = check_files(&files);
+    for (file, outcome) in &report.entries {
+        assert!(
+            matches!(outcome, GoldenOutcome::Pass),
+            "{}: {outcome:?}",
+            file.display()
+        );
+    }
+}
+
+#[test]
+fn stage01_examples() {
+    check_examples("stage01_examples");
+}
+
+#[test]
+fn stage02_examples() {
+    check_examples("stage02_examples");
+}